@@ -5,7 +5,24 @@
 //! [`Tablet`] - Single title
 //! [`Shard`] - Single note from `Tablet`
 //! [`Registry`] - Collection of all `Tablet`s and `Shard`s available
+//! [`RegistryBuilder`] - Builder of the [`CustomRegistry`] over your own notes
 //! [`Transcriptor`] - Special tool for reading `Tablet`s and `Shard`s in `markdown` format
+//! [`ReadOptions`] - Rendering options for the `Transcriptor`
+//! [`CachedTablet`] - `Tablet` which reads its contents only once
+//! [`ContentProvider`] - Source of the note lines, like [`FsProvider`] or [`InMemoryProvider`]
+//! [`TabletError`] - Reason why a `Tablet` could not be constructed
+//! [`ReadError`] - Reason why a `Tablet` could not be read, with its path and line
+//! [`ValidationReport`] - Issues found by the `Registry` validation
+//!
+//! Optional `regex` feature enables `Registry::search_regex`
+//! Optional `mmap` feature enables `Transcriptor::read_mmap`
+//! Optional `rayon` feature enables `Registry::par_catalog` and `Registry::search_parallel`
+//! Optional `serde` feature implements `Serialize` for [`RegistryStats`]
+//! Optional `syntect` feature enables `Transcriptor::read_highlighted_html`
+//! Optional `strict-shard` feature adds the `strict` module with a `Shard` type distinct from the `Tablet`
+//!
+//! Default `std` feature enables everything which reads the notes from disk. Without it only
+//! [`Tablet`], [`Shards`], [`ReadOptions`], [`RegistryStats`] and the pure [`parse`] module are available
 //!
 //! # Examples
 //!
@@ -21,23 +38,65 @@
 //! let shard_markdown_string = Transcriptor::read(&tablet_shard);
 //! ```
 
-use std::fs::File;
-use std::io::{BufRead, BufReader, Result};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod parse;
+#[cfg(feature = "strict-shard")]
+pub mod strict;
+
+use alloc::vec::IntoIter;
+use core::iter::FusedIterator;
+#[cfg(feature = "std")]
+use std::cell::OnceCell;
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(feature = "std")]
+use std::fs::{self, File};
+#[cfg(feature = "std")]
+use std::io::{self, BufRead, BufReader, ErrorKind, Result, Write};
+#[cfg(feature = "std")]
 use std::path::Path;
-use std::vec::IntoIter;
+#[cfg(feature = "std")]
+use std::str::FromStr;
+#[cfg(feature = "std")]
+use std::sync::{Arc, PoisonError, RwLock};
+#[cfg(feature = "std")]
+use std::time::Duration;
 
+#[cfg(feature = "std")]
 mod registry;
 
+#[cfg(feature = "std")]
 const TABLET_UNREADABLE_MSG: &str = "The tablet is expected to be readable!";
+#[cfg(feature = "std")]
 const TABLET_BROKEN_NAME_MSG: &str = "The tablet is expected to have a valid name!";
+#[cfg(feature = "std")]
+const SHARD_OUT_OF_BOUNDS_MSG: &str = "The shard is out of the tablet bounds!";
 
 /// `Tablet` represents a single title. Contains only path to the title file, start and end lines, and methods representing common info
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Tablet(&'static str, (usize, usize));
 /// `Shard` is logically different from the [`Tablet`], but actually is just an alias. Represents one note from the [`Tablet`]
+///
+/// Because of the alias, the compiler accepts a whole [`Tablet`] wherever a `Shard` is expected.
+/// Use [`Tablet::is_whole`] and [`Tablet::as_shard`] to check which one you actually hold,
+/// or the distinct `strict::Shard` type of the optional `strict-shard` feature
 pub type Shard = Tablet;
 
 impl Tablet {
+    /// creates a [`Tablet`] over the inclusive `start..=end` lines of the note at `path`, without touching the file.
+    /// Panics in debug builds if `start` is after `end`
+    pub const fn new(path: &'static str, start: usize, end: usize) -> Self {
+        debug_assert!(start <= end, "`Tablet` range starts after its end");
+        Tablet(path, (start, end))
+    }
+
     /// returns path to the note as `&str`
     pub fn path_str(&self) -> &'static str {
         self.0
@@ -53,182 +112,4113 @@ impl Tablet {
         self.1.1
     }
 
+    /// count of lines in the note
+    pub fn length(&self) -> usize {
+        self.end() - self.start() + 1
+    }
+}
+
+#[cfg(feature = "std")]
+impl Tablet {
     /// returns path to the note as &[`Path`]
     pub fn path(&self) -> &'static Path {
         Path::new(self.path_str())
     }
 
-    /// count of lines in the note
-    pub fn length(&self) -> usize {
-        self.end() - self.start() + 1
+    /// fraction of the whole note, from 0.0 to 1.0, which precedes this [`Shard`] start
+    pub fn reading_position(&self) -> Result<f64> {
+        let whole = Tablet::from_path(self.path_str())?;
+        Ok(self.start().min(whole.length()) as f64 / whole.length() as f64)
+    }
+
+    /// the whole [`Tablet`] this [`Shard`] belongs to. Reopens the note file to count its full length, same as [`Tablet::from_path`]
+    pub fn parent_tablet(&self) -> Result<Tablet> {
+        Ok(Tablet::from_path(self.path_str())?)
+    }
+
+    /// [`Tablet::length`] of this [`Tablet`], if it is a bundled note already counted by [`Registry::catalog`] or [`Registry::reload`].
+    /// Never touches the disk and returns `None` if the note is not cached yet, or if this is only a [`Shard`] of it
+    pub fn length_cached(&self) -> Option<usize> {
+        Registry::cached(self.path_str())
+            .map(|(tablet, _)| tablet)
+            .filter(|tablet| tablet == self)
+            .map(|tablet| tablet.length())
+    }
+
+    /// checks if this [`Tablet`] covers the whole note file, rather than a single [`Shard`] of it
+    pub fn is_whole(&self) -> Result<bool> {
+        Ok(Tablet::from_path(self.path_str())? == *self)
+    }
+
+    /// returns itself as a [`Shard`] if it covers exactly one section of the note, or `None` if it spans several sections
+    pub fn as_shard(&self) -> Result<Option<Shard>> {
+        let segments = Transcriptor::segmentation(self)?;
+        match segments.as_slice() {
+            [segment] if *segment == self.1 => Ok(Some(*self)),
+            _ => Ok(None),
+        }
+    }
+
+    /// checks if the note file still exists. Never opens the file, so unlike [`Tablet::from_path`] it can't fail
+    pub fn path_exists(&self) -> bool {
+        self.path().exists()
     }
 
     /// name of the note. Originates from the filename
     pub fn name(&self) -> &'static str {
-        self.path()
+        Self::stem(self.path_str())
+    }
+
+    // name of the note located at `path`, without opening the file
+    fn stem(path: &'static str) -> &'static str {
+        Path::new(path)
             .file_stem()
             .expect(TABLET_BROKEN_NAME_MSG)
             .to_str()
             .expect(TABLET_BROKEN_NAME_MSG)
     }
 
-    /// returns [`Shards`] iterator over every [`Shard`] in this title
+    /// identity of the [`Shard`] as path plus index of the section in the whole note.
+    /// Unlike the [`Hash`] of [`Shard`], it survives edits that shift line numbers, while the sections order is unchanged
+    pub fn stable_key(&self) -> Result<(String, usize)> {
+        let whole = Tablet::from_path(self.path_str())?;
+        let index = Transcriptor::segmentation(&whole)?
+            .iter()
+            .position(|&(start, end)| start <= self.start() && self.start() <= end)
+            .ok_or_else(|| io::Error::new(ErrorKind::InvalidInput, SHARD_OUT_OF_BOUNDS_MSG))?;
+        Ok((self.path_str().to_string(), index))
+    }
+
+    /// builds the whole [`Tablet`] from the note file located at `path`, counting its lines
+    pub fn from_path(path: &'static str) -> std::result::Result<Tablet, TabletError> {
+        let data = File::open(Path::new(path))?;
+        let length = BufReader::new(data).lines().count();
+        match length {
+            0 => Err(TabletError::Empty(path.to_string())),
+            _ => Ok(Tablet(path, (0, length - 1))),
+        }
+    }
+
+    /// checks if the note is called `name`, ignoring ASCII case
+    pub fn name_matches(&self, name: &str) -> bool {
+        self.name().eq_ignore_ascii_case(name)
+    }
+
+    /// [`Tablet::length`] of every [`Shard`] in order, computed from a single segmentation pass
+    pub fn shard_lengths(&self) -> Result<Vec<usize>> {
+        Ok(Transcriptor::segmentation(self)?
+            .into_iter()
+            .map(|(start, end)| end - start + 1)
+            .collect())
+    }
+
+    /// returns [`Shards`] iterator over every [`Shard`] in this title. For a [`Registry::catalog`] entry they come from
+    /// the same cached snapshot as [`Registry::heap`], otherwise the note file is split again
     pub fn shards(&self) -> Shards {
         self.into()
     }
-}
 
-/// `Transcriptor` represents a special tool for reading [`Tablet`]s and [`Shard`]s in the `markdown` format
-pub struct Transcriptor;
+    /// checks if the formatted note has no visible content. Unlike [`Tablet::length`], blank lines do not count
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(Transcriptor::read(self)?.trim().is_empty())
+    }
 
-impl Transcriptor {
-    const SEPARATOR: &str = "-----";
+    /// checks if both [`Tablet`]s render to exactly the same text with [`Transcriptor::read`], wherever they are located
+    pub fn eq_content(&self, other: &Tablet) -> Result<bool> {
+        Ok(Transcriptor::read(self)? == Transcriptor::read(other)?)
+    }
 
-    // formats one line to match `markdown` format
-    fn line_fmt(line: &str) -> String {
-        let mut formatted = String::new();
-        formatted.push_str(
-            line.replace("//!", "")
-                .replace("```should_panic", "```rust")
-                .replace("```no_run", "```rust")
-                .trim(),
-        );
-        formatted.push('\n');
-        formatted
+    /// checks if both [`Tablet`]s render to the same text with [`Transcriptor::read`] after normalization:
+    /// every run of whitespace, line breaks included, is collapsed into a single space, and the ends are trimmed
+    pub fn eq_content_normalized(&self, other: &Tablet) -> Result<bool> {
+        let normalize = |tablet: &Tablet| -> Result<String> {
+            Ok(Transcriptor::read(tablet)?
+                .split_whitespace()
+                .collect::<Vec<&str>>()
+                .join(" "))
+        };
+        Ok(normalize(self)? == normalize(other)?)
     }
 
-    // finds all separators in the `Tablet`
-    fn segmentation(tablet: &Tablet) -> Result<Vec<(usize, usize)>> {
-        let mut segments: Vec<(usize, usize)> = Vec::new();
-        let mut ptr: usize = tablet.start();
-        let data = File::open(tablet.path())?;
-        for (num, line) in BufReader::new(data)
-            .lines()
-            .skip(tablet.start())
-            .take(tablet.length())
+    /// count of [`Shard`]s with visible content. Unlike `shards().count()`, skips segments left empty by separators
+    pub fn section_count_excluding_empty(&self) -> Result<usize> {
+        Ok(self.nonempty_shards()?.len())
+    }
+
+    /// every [`Shard`] in this title with visible content. Use [`Tablet::shards`] to get every segment
+    pub fn nonempty_shards(&self) -> Result<Vec<Shard>> {
+        let mut shards = Vec::new();
+        for shard in self.shards() {
+            if !shard.is_empty()? {
+                shards.push(shard);
+            }
+        }
+        Ok(shards)
+    }
+
+    /// byte offsets of every [`Shard`] in this title as `(start, end)`, where `start` is inclusive and `end` is exclusive.
+    /// Offsets account for line breaks, so they can be used to [`Seek`](std::io::Seek) directly to the section
+    pub fn byte_segments(&self) -> Result<Vec<(u64, u64)>> {
+        let mut reader = BufReader::new(File::open(self.path())?);
+        let mut offsets: Vec<u64> = vec![0];
+        let mut lines: Vec<String> = Vec::new();
+        let mut buf: Vec<u8> = Vec::new();
+        while offsets.len() <= self.end() + 1 {
+            buf.clear();
+            let size = reader.read_until(b'\n', &mut buf)?;
+            if size == 0 {
+                break;
+            }
+            if offsets.len() > self.start() {
+                lines.push(String::from_utf8_lossy(&buf).trim_end().to_string());
+            }
+            offsets.push(offsets[offsets.len() - 1] + size as u64);
+        }
+        Ok(parse::segments(&lines, self.start())
+            .into_iter()
+            .map(|(start, end)| (offsets[start], offsets[end + 1]))
+            .collect())
+    }
+
+    /// checks if the note lines are valid UTF-8. Invalid content gives `Ok(false)`, only I/O failures are errors
+    pub fn is_valid_utf8(&self) -> Result<bool> {
+        let mut reader = BufReader::new(File::open(self.path())?);
+        let mut buf: Vec<u8> = Vec::new();
+        for num in 0..=self.end() {
+            buf.clear();
+            if reader.read_until(b'\n', &mut buf)? == 0 {
+                break;
+            }
+            if num >= self.start() && std::str::from_utf8(&buf).is_err() {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// streams every `char` of the contents formatted by [`Transcriptor::read`], so `🦀` is a single item.
+    /// The note is read once up front, so I/O errors are returned here rather than mid-stream
+    pub fn chars(&self) -> Result<impl Iterator<Item = char>> {
+        let contents = Transcriptor::read(self)?;
+        let mut position = 0;
+        Ok(std::iter::from_fn(move || {
+            let c = contents[position..].chars().next()?;
+            position += c.len_utf8();
+            Some(c)
+        }))
+    }
+
+    /// every word of the note prose in order, skipping code blocks. Words are split by whitespace,
+    /// and markdown markers without any alphanumeric chars, like `#` or `-`, are skipped
+    pub fn words(&self) -> Result<Vec<String>> {
+        let lines = Transcriptor::formatted_lines(self)?;
+        Ok(Transcriptor::prose_words(&lines)
+            .map(String::from)
+            .collect())
+    }
+
+    /// count of [`Tablet::words`]
+    pub fn word_count(&self) -> Result<usize> {
+        let lines = Transcriptor::formatted_lines(self)?;
+        Ok(Transcriptor::prose_words(&lines).count())
+    }
+
+    /// estimated time to read [`Tablet::words`] at `wpm` words per minute. A `wpm` of 0 counts as 1
+    pub fn reading_time(&self, wpm: usize) -> Result<Duration> {
+        Ok(Transcriptor::reading_time(self.word_count()?, wpm))
+    }
+
+    /// text of every intra-doc link in the note prose, like `String` for [`String`], in order and with repetitions
+    pub fn links(&self) -> Result<Vec<String>> {
+        Ok(Transcriptor::prose_lines(self)?
+            .iter()
+            .flat_map(|line| Transcriptor::line_links(line))
+            .map(String::from)
+            .collect())
+    }
+
+    /// count of distinct [`Tablet::links`], compared exactly
+    pub fn distinct_links_count(&self) -> Result<usize> {
+        Ok(self.links()?.into_iter().collect::<HashSet<String>>().len())
+    }
+
+    /// checks if any intra-doc link in the note is exactly `target`, like `String` for [`String`]
+    pub fn contains_link_to(&self, target: &str) -> Result<bool> {
+        Ok(Transcriptor::prose_lines(self)?
+            .iter()
+            .any(|line| Transcriptor::line_links(line).any(|link| link == target)))
+    }
+
+    /// absolute line numbers of every separator between [`Shard`]s in this [`Tablet`]. Lines inside code blocks are skipped
+    pub fn separator_lines(&self) -> Result<Vec<usize>> {
+        let lines = Transcriptor::formatted_lines(self)?;
+        Ok(lines
+            .iter()
+            .zip(parse::code_mask(&lines))
             .enumerate()
-        {
-            if line?.contains(Self::SEPARATOR) {
-                segments.push((ptr, num - 1));
-                ptr = num + 1;
+            .filter(|(_, (line, in_code))| !in_code && parse::is_formatted_separator(line))
+            .map(|(num, _)| self.start() + num)
+            .collect())
+    }
+
+    /// body of every complete `rust` code block in the note, in order
+    pub fn code_blocks(&self) -> Result<Vec<String>> {
+        let mut lines = Transcriptor::formatted_iter(self)?;
+        let mut blocks = Vec::new();
+        while let Some(block) = Transcriptor::next_code_block(&mut lines)? {
+            blocks.push(block);
+        }
+        Ok(blocks)
+    }
+
+    /// body of the first complete `rust` code block in the note, or `None` if there is no code.
+    /// Stops reading right after the block
+    pub fn first_code_block(&self) -> Result<Option<String>> {
+        Transcriptor::next_code_block(&mut Transcriptor::formatted_iter(self)?)
+    }
+
+    /// count of complete `rust` code blocks within this [`Shard`], same as `code_blocks()?.len()` but without collecting their bodies
+    pub fn example_count(&self) -> Result<usize> {
+        let mut count = 0;
+        let mut fence: Option<bool> = None;
+        for line in Transcriptor::formatted_iter(self)? {
+            let line = line?;
+            if !parse::is_fence(&line) {
+                continue;
             }
+            fence = match fence {
+                Some(is_rust) => {
+                    count += usize::from(is_rust);
+                    None
+                }
+                None => Some(parse::is_rust_fence(&line)),
+            };
         }
-        segments.push((ptr, tablet.end()));
-        Ok(segments)
+        Ok(count)
     }
 
-    /// reads the contents of [`Tablet`] or [`Shard`], formats it to match the `markdown` format, and returns as [`String`]
-    pub fn read(tablet: &Tablet) -> Result<String> {
-        let mut contents = String::new();
-        let data = File::open(tablet.path())?;
-        for line in BufReader::new(data)
-            .lines()
-            .skip(tablet.start())
-            .take(tablet.length())
-        {
-            let line = Self::line_fmt(line?.as_str());
-            contents.push_str(line.as_str());
+    /// checks if any line inside a `rust` code block contains `needle`, case-sensitively. Prose and other code blocks are skipped.
+    /// Stops reading at the first match
+    pub fn code_contains(&self, needle: &str) -> Result<bool> {
+        let mut fence: Option<bool> = None;
+        for line in Transcriptor::formatted_iter(self)? {
+            let line = line?;
+            if !parse::is_fence(&line) {
+                if fence == Some(true) && line.contains(needle) {
+                    return Ok(true);
+                }
+                continue;
+            }
+            fence = match fence {
+                Some(_) => None,
+                None => Some(parse::is_rust_fence(&line)),
+            };
         }
-        Ok(contents.trim().to_string())
+        Ok(false)
+    }
+
+    /// human friendly name of the note. `ownership_system` becomes `Ownership System`
+    pub fn name_pretty(&self) -> String {
+        self.name()
+            .split(['_', '-'])
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let mut chars = word.chars();
+                let first = chars.next().map(|c| c.to_uppercase().to_string());
+                first.unwrap_or_default() + chars.as_str()
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// text of the first `# ` heading in the note, if there is one
+    pub fn title(&self) -> Result<Option<String>> {
+        Ok(Transcriptor::title(&Transcriptor::formatted_lines(self)?))
+    }
+
+    /// best display title of the note: the first `# ` heading, or [`Tablet::name_pretty`] if there is none. Never empty
+    pub fn display_name(&self) -> Result<String> {
+        Ok(self.title()?.unwrap_or_else(|| self.fallback_name()))
+    }
+
+    /// label of the [`Shard`]: its first `# ` heading, or `"{name} — section {n}"` counting sections from 1. Never empty
+    pub fn display_title(&self) -> Result<String> {
+        match self.title()? {
+            Some(title) => Ok(title),
+            None => {
+                let (_, index) = self.stable_key()?;
+                Ok(format!("{} — section {}", self.fallback_name(), index + 1))
+            }
+        }
+    }
+
+    /// `(title, slug)` of every [`Shard`], where the slug is the `id` of its first `# ` heading in [`Transcriptor::read_html`].
+    /// A section without such heading gets its [`Tablet::display_title`] and the `section-{n}` slug, counting from 1
+    pub fn section_anchors(&self) -> Result<Vec<(String, String)>> {
+        let mut slugs = Slugs::default();
+        let mut anchors = Vec::new();
+        for (index, shard) in self.shards().enumerate() {
+            let lines = Transcriptor::formatted_lines(&shard)?;
+            let mut anchor = None;
+            for (line, in_code) in lines.iter().zip(parse::code_mask(&lines)) {
+                if let Some((level, text)) = parse::heading(line).filter(|_| !in_code) {
+                    let slug = slugs.next(text);
+                    if level == 1 && !text.is_empty() && anchor.is_none() {
+                        anchor = Some((text.to_string(), slug));
+                    }
+                }
+            }
+            anchors.push(match anchor {
+                Some(anchor) => anchor,
+                None => (shard.display_title()?, format!("section-{}", index + 1)),
+            });
+        }
+        Ok(anchors)
+    }
+
+    /// `(front, back)` flashcard of the [`Shard`]: the front is its first heading, or the pretty [`Tablet::name`] without one,
+    /// and the back is the rest rendered like [`Transcriptor::read`], without the heading line
+    pub fn flashcard(&self) -> Result<(String, String)> {
+        let mut lines = Transcriptor::formatted_lines(self)?;
+        let heading = lines
+            .iter()
+            .zip(parse::code_mask(&lines))
+            .position(|(line, in_code)| {
+                !in_code && parse::heading(line).is_some_and(|(_, text)| !text.is_empty())
+            });
+        let front = match heading {
+            Some(index) => {
+                let line = lines.remove(index);
+                parse::heading(&line)
+                    .map(|(_, text)| text.to_string())
+                    .unwrap_or_default()
+            }
+            None => self.fallback_name(),
+        };
+        Ok((front, Transcriptor::render(lines, &ReadOptions::default())))
+    }
+
+    // pretty name, or the raw name when the pretty one is empty
+    fn fallback_name(&self) -> String {
+        match self.name_pretty() {
+            pretty if pretty.is_empty() => self.name().to_string(),
+            pretty => pretty,
+        }
+    }
+
+    /// every heading of the note as `(level, text)`, where `level` is the count of leading `#`.
+    /// Lines inside code blocks, like `# hidden` or `#[derive(Debug)]`, are never headings
+    pub fn outline(&self) -> Result<Vec<(usize, String)>> {
+        Ok(Transcriptor::headings(&Transcriptor::formatted_lines(
+            self,
+        )?))
+    }
+
+    /// formatted content of the absolute file `line`, numbered like [`Tablet::start`] and [`Tablet::end`],
+    /// or `None` if the line is out of this [`Tablet`] range
+    pub fn line_at(&self, line: usize) -> Result<Option<String>> {
+        if line < self.start() || line > self.end() {
+            return Ok(None);
+        }
+        Transcriptor::formatted_iter(self)?
+            .nth(line - self.start())
+            .transpose()
+    }
+
+    /// first non-empty formatted line, or `None` if there is no such line. Stops reading the file as soon as it is found
+    pub fn first_line(&self) -> Result<Option<String>> {
+        for line in Transcriptor::formatted_iter(self)? {
+            let line = line?;
+            if !line.trim().is_empty() {
+                return Ok(Some(line));
+            }
+        }
+        Ok(None)
+    }
+
+    /// every formatted line containing `needle`, ignoring case, paired with its absolute line number
+    pub fn lines_containing(&self, needle: &str) -> Result<Vec<(usize, String)>> {
+        let needle = needle.to_lowercase();
+        Ok(self
+            .enumerated_lines()?
+            .into_iter()
+            .filter(|(_, line)| line.to_lowercase().contains(&needle))
+            .collect())
+    }
+
+    /// formatted first line containing `needle`, ignoring case, together with up to `radius` lines before and after it,
+    /// clamped to this [`Shard`] bounds. `None` if there is no match
+    pub fn context(&self, needle: &str, radius: usize) -> Result<Option<String>> {
+        let needle = needle.to_lowercase();
+        let lines = Transcriptor::formatted_lines(self)?;
+        Ok(lines
+            .iter()
+            .position(|line| line.to_lowercase().contains(&needle))
+            .map(|found| {
+                let from = found.saturating_sub(radius);
+                let to = found.saturating_add(radius).min(lines.len() - 1);
+                lines[from..=to].join("\n")
+            }))
+    }
+
+    /// checks if the formatted note contains every one of `needles`, ignoring case. The note is read only once
+    pub fn matches_all(&self, needles: &[&str]) -> Result<bool> {
+        let contents = Transcriptor::read(self)?.to_lowercase();
+        Ok(needles
+            .iter()
+            .all(|needle| contents.contains(&needle.to_lowercase())))
+    }
+
+    /// checks if the formatted note contains any of `needles`, ignoring case. The note is read only once
+    pub fn matches_any(&self, needles: &[&str]) -> Result<bool> {
+        let contents = Transcriptor::read(self)?.to_lowercase();
+        Ok(needles
+            .iter()
+            .any(|needle| contents.contains(&needle.to_lowercase())))
+    }
+
+    /// every formatted line paired with its absolute line number, numbered like [`Tablet::start`] and [`Tablet::end`]
+    pub fn enumerated_lines(&self) -> Result<Vec<(usize, String)>> {
+        Ok(Transcriptor::formatted_lines(self)?
+            .into_iter()
+            .enumerate()
+            .map(|(num, line)| (self.start() + num, line))
+            .collect())
+    }
+
+    /// absolute `(start, end)` range without the leading and trailing lines which are blank after formatting.
+    /// Returns the original range if every line is blank
+    pub fn trimmed_range(&self) -> Result<(usize, usize)> {
+        let lines = self.enumerated_lines()?;
+        let mut filled = lines.iter().filter(|(_, line)| !line.trim().is_empty());
+        Ok(match (filled.next(), filled.next_back()) {
+            (Some(&(start, _)), Some(&(end, _))) => (start, end),
+            (Some(&(start, _)), None) => (start, start),
+            _ => self.1,
+        })
+    }
+
+    /// tags of the note, declared with the `@tags: first, second` annotation line
+    pub fn tags(&self) -> Result<Vec<String>> {
+        Ok(Transcriptor::metadata(self, Transcriptor::TAGS_KEY)?
+            .map(|tags| {
+                tags.split(',')
+                    .map(str::trim)
+                    .filter(|tag| !tag.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// level of the note, declared with the `@level: beginner` annotation line
+    pub fn level(&self) -> Result<Option<String>> {
+        Transcriptor::metadata(self, Transcriptor::LEVEL_KEY)
     }
 }
 
-/// `Shards` is an iterator over every [`Shard`] from the [`Tablet`]
+/// Resolves the [`Tablet`] by its name, like `"ownership_system".parse::<Tablet>()`.
+/// Takes the [`Tablet`] cached by [`Registry::catalog`] if there is one, otherwise opens the found note file to count its lines,
+/// so it fails only if that note is missing or unreadable
+#[cfg(feature = "std")]
+impl FromStr for Tablet {
+    type Err = TabletError;
+
+    fn from_str(name: &str) -> std::result::Result<Self, Self::Err> {
+        let path = registry::TABLETS
+            .iter()
+            .find(|&&path| Tablet::stem(path).eq_ignore_ascii_case(name))
+            .ok_or_else(|| TabletError::NotFound(name.to_string()))?;
+        Registry::tablet(path)
+    }
+}
+
+/// Builds the [`Tablet`] from the note file path, same as [`Tablet::from_path`]
+#[cfg(feature = "std")]
+impl TryFrom<&'static str> for Tablet {
+    type Error = TabletError;
+
+    fn try_from(path: &'static str) -> std::result::Result<Self, Self::Error> {
+        Tablet::from_path(path)
+    }
+}
+
+#[cfg(feature = "std")]
+impl AsRef<Path> for Tablet {
+    fn as_ref(&self) -> &Path {
+        self.path()
+    }
+}
+
+/// `CachedTablet` wraps the [`Tablet`] and memoizes its `markdown` contents on the first [`CachedTablet::read`]
+#[cfg(feature = "std")]
 #[derive(Debug, Clone)]
-pub struct Shards {
-    origin: Tablet,
-    segments: IntoIter<(usize, usize)>,
+pub struct CachedTablet {
+    tablet: Tablet,
+    contents: OnceCell<String>,
 }
 
-impl Iterator for Shards {
-    type Item = Shard;
-    fn next(&mut self) -> Option<Self::Item> {
-        Some(Tablet(self.origin.path_str(), self.segments.next()?))
+#[cfg(feature = "std")]
+impl CachedTablet {
+    /// the wrapped [`Tablet`]
+    pub fn tablet(&self) -> Tablet {
+        self.tablet
+    }
+
+    /// reads the [`Tablet`] with [`Transcriptor::read`] on the first call, and returns the cached contents afterwards
+    pub fn read(&self) -> Result<&str> {
+        if let Some(contents) = self.contents.get() {
+            return Ok(contents);
+        }
+        let contents = Transcriptor::read(&self.tablet)?;
+        Ok(self.contents.get_or_init(|| contents))
     }
 }
 
-impl From<Tablet> for Shards {
+#[cfg(feature = "std")]
+impl From<Tablet> for CachedTablet {
     fn from(tablet: Tablet) -> Self {
-        Shards {
-            origin: tablet,
-            segments: Transcriptor::segmentation(&tablet)
-                .expect(TABLET_UNREADABLE_MSG)
-                .into_iter(),
+        CachedTablet {
+            tablet,
+            contents: OnceCell::new(),
         }
     }
 }
 
-impl From<&Tablet> for Shards {
-    fn from(tablet: &Tablet) -> Self {
-        Self::from(*tablet)
+/// `TabletError` describes why a [`Tablet`] could not be constructed
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum TabletError {
+    /// no [`Tablet`] with the given name exists in the [`Registry`]
+    NotFound(String),
+    /// the note file at the given path has no lines
+    Empty(String),
+    /// the note file could not be read
+    Io(io::Error),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for TabletError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TabletError::NotFound(name) => write!(f, "no tablet named `{name}` found"),
+            TabletError::Empty(path) => write!(f, "tablet at `{path}` is empty"),
+            TabletError::Io(err) => write!(f, "tablet is unreadable: {err}"),
+        }
     }
 }
 
-/// `Registry` represents a collection of all [`Tablet`]s and [`Shard`]s available
-pub struct Registry;
+#[cfg(feature = "std")]
+impl Error for TabletError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TabletError::NotFound(_) | TabletError::Empty(_) => None,
+            TabletError::Io(err) => Some(err),
+        }
+    }
+}
 
-impl Registry {
-    fn tablet(path: &'static str) -> Tablet {
-        let data = File::open(Path::new(path)).expect(TABLET_UNREADABLE_MSG);
-        let length = BufReader::new(data).lines().count();
-        Tablet(path, (0, length - 1))
+#[cfg(feature = "std")]
+impl From<io::Error> for TabletError {
+    fn from(err: io::Error) -> Self {
+        TabletError::Io(err)
     }
+}
 
-    /// returns all available [`Tablet`]s in the form of [`Vec`]. Use [`Transcriptor`] to read from the [`Tablet`]
-    pub fn catalog() -> Vec<Tablet> {
-        registry::TABLETS
-            .iter()
-            .map(|&path| Self::tablet(path))
-            .collect()
+#[cfg(feature = "std")]
+impl From<TabletError> for io::Error {
+    fn from(err: TabletError) -> Self {
+        match err {
+            TabletError::Io(err) => err,
+            TabletError::NotFound(_) => io::Error::new(ErrorKind::NotFound, err),
+            TabletError::Empty(_) => io::Error::new(ErrorKind::InvalidData, err),
+        }
     }
+}
 
-    /// returns all available [`Shard`]s in the form of [`Vec`]. Use [`Transcriptor`] to read from the [`Shard`]
-    pub fn heap() -> Vec<Shard> {
-        Self::catalog()
-            .iter()
-            .flat_map(|tablet| tablet.shards())
-            .collect()
+/// `ReadError` describes why [`Transcriptor`] could not read a [`Tablet`], pointing at its note file
+/// and, if the failure happened mid-file, the absolute line being read
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ReadError {
+    path: String,
+    line: Option<usize>,
+    source: io::Error,
+}
+
+#[cfg(feature = "std")]
+impl ReadError {
+    // wraps the `source` error of reading the `Tablet`, optionally at the absolute `line`
+    fn new(tablet: &Tablet, line: Option<usize>, source: io::Error) -> Self {
+        Self::at(tablet.path(), line, source)
+    }
+
+    // wraps the `source` error of reading any file at `path`, optionally at the absolute `line`
+    fn at(path: &Path, line: Option<usize>, source: io::Error) -> Self {
+        ReadError {
+            path: path.display().to_string(),
+            line,
+            source,
+        }
+    }
+
+    /// path to the note file which failed to read
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// absolute line number being read when the failure happened, or `None` if the note file could not be opened at all
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// kind of the underlying [`io::Error`]
+    pub fn kind(&self) -> ErrorKind {
+        self.source.kind()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+#[cfg(feature = "std")]
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(
+                f,
+                "failed to read `{}` at line {line}: {}",
+                self.path, self.source
+            ),
+            None => write!(f, "failed to read `{}`: {}", self.path, self.source),
+        }
+    }
+}
 
-    // Prints every single Tablet
-    #[test]
-    fn print_catalog() {
-        let cat = Registry::catalog();
-        print!("{}", "\n".repeat(7));
-        println!("{}", "==============================".repeat(4));
-        println!("\nVec Catalog: {:#?} Len: {}\n", cat, cat.len());
-        cat.iter().for_each(|tablet| {
-            println!(
-                "\n################# TABLET {name} #################\n[[{contents}]]",
-                name = tablet.name().to_ascii_uppercase(),
-                contents = Transcriptor::read(tablet).expect(TABLET_UNREADABLE_MSG)
-            )
-        });
-        println!("\n{}", "==============================".repeat(4));
-        print!("{}", "\n".repeat(7));
+#[cfg(feature = "std")]
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
     }
+}
 
-    // Prints every single Shard
-    #[test]
-    fn print_heap() {
-        let heap = Registry::heap();
-        print!("{}", "\n".repeat(7));
-        println!("{}", "==============================".repeat(4));
-        println!("\nVec Heap: {:#?} Len: {}\n", heap, heap.len());
-        heap.iter().for_each(|shard| {
-            println!(
-                "\n################# shard {name}-{line} #################\n[[{contents}]]",
-                name = shard.name(),
-                line = shard.start(),
-                contents = Transcriptor::read(shard).expect(TABLET_UNREADABLE_MSG)
-            )
-        });
-        println!("\n{}", "==============================".repeat(4));
-        print!("{}", "\n".repeat(7));
+#[cfg(feature = "std")]
+impl From<ReadError> for io::Error {
+    fn from(err: ReadError) -> Self {
+        io::Error::new(err.kind(), err)
+    }
+}
+
+/// `SearchError` describes why [`Registry::search_regex`] failed
+#[cfg(feature = "regex")]
+#[derive(Debug)]
+pub enum SearchError {
+    /// the pattern is not a valid regular expression
+    Regex(regex::Error),
+    /// some [`Shard`] could not be read
+    Io(io::Error),
+}
+
+#[cfg(feature = "regex")]
+impl fmt::Display for SearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchError::Regex(err) => write!(f, "invalid search pattern: {err}"),
+            SearchError::Io(err) => write!(f, "shard is unreadable: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Error for SearchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SearchError::Regex(err) => Some(err),
+            SearchError::Io(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl From<regex::Error> for SearchError {
+    fn from(err: regex::Error) -> Self {
+        SearchError::Regex(err)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl From<io::Error> for SearchError {
+    fn from(err: io::Error) -> Self {
+        SearchError::Io(err)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl From<ReadError> for SearchError {
+    fn from(err: ReadError) -> Self {
+        SearchError::Io(err.into())
+    }
+}
+
+/// `ValidationIssue` is a single problem found by [`Registry::validate`]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationIssue {
+    /// error: the note file at the path could not be read, with the reason
+    Unreadable(&'static str, String),
+    /// warning: the note at the path has no visible content
+    Empty(&'static str),
+}
+
+#[cfg(feature = "std")]
+impl ValidationIssue {
+    /// path to the note file with the issue
+    pub fn path(&self) -> &'static str {
+        match self {
+            ValidationIssue::Unreadable(path, _) | ValidationIssue::Empty(path) => path,
+        }
+    }
+
+    /// checks if the issue is an error rather than a warning
+    pub fn is_error(&self) -> bool {
+        matches!(self, ValidationIssue::Unreadable(..))
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationIssue::Unreadable(_, reason) => write!(f, "error: unreadable file: {reason}"),
+            ValidationIssue::Empty(_) => write!(f, "warning: empty tablet"),
+        }
+    }
+}
+
+/// `ValidationReport` is a result of [`Registry::validate`]. Displays as a summary grouped by [`Tablet`]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct ValidationReport {
+    issues: Vec<ValidationIssue>,
+}
+
+#[cfg(feature = "std")]
+impl ValidationReport {
+    /// checks if there are no errors. Warnings do not count
+    pub fn is_ok(&self) -> bool {
+        !self.issues.iter().any(ValidationIssue::is_error)
+    }
+
+    /// every issue found, in the [`Registry`] order
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut current: Option<&str> = None;
+        for issue in &self.issues {
+            if current != Some(issue.path()) {
+                writeln!(f, "{}:", issue.path())?;
+                current = Some(issue.path());
+            }
+            writeln!(f, "    {issue}")?;
+        }
+        let errors = self.issues.iter().filter(|issue| issue.is_error()).count();
+        let warnings = self.issues.len() - errors;
+        write!(f, "{errors} error(s), {warnings} warning(s)")
+    }
+}
+
+/// `ContentProvider` supplies the source lines of note files. Lets [`Transcriptor::read_from`] and [`CustomRegistry`]
+/// read notes from somewhere other than the filesystem, like a browser without one. Other [`Tablet`] and [`Transcriptor`]
+/// methods always read the filesystem, so use the [`CustomRegistry`] ones with such notes
+#[cfg(feature = "std")]
+pub trait ContentProvider {
+    /// returns every source line of the note at `path`
+    fn read_lines(&self, path: &str) -> Result<Vec<String>>;
+}
+
+/// `FsProvider` reads the notes from the filesystem. Used by default
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FsProvider;
+
+#[cfg(feature = "std")]
+impl ContentProvider for FsProvider {
+    fn read_lines(&self, path: &str) -> Result<Vec<String>> {
+        BufReader::new(File::open(path)?).lines().collect()
+    }
+}
+
+/// `InMemoryProvider` serves the notes from memory, e.g. embedded into the binary with `include_str!`
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryProvider {
+    notes: HashMap<String, Vec<String>>,
+}
+
+#[cfg(feature = "std")]
+impl InMemoryProvider {
+    /// empty provider without any notes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// adds the note with `contents`, served at `path`
+    pub fn add(mut self, path: &str, contents: &str) -> Self {
+        self.notes.insert(
+            path.to_string(),
+            contents.lines().map(String::from).collect(),
+        );
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl ContentProvider for InMemoryProvider {
+    fn read_lines(&self, path: &str) -> Result<Vec<String>> {
+        self.notes.get(path).cloned().ok_or_else(|| {
+            io::Error::new(ErrorKind::NotFound, format!("no note at {path} in memory"))
+        })
+    }
+}
+
+/// `Transcriptor` represents a special tool for reading [`Tablet`]s and [`Shard`]s in the `markdown` format
+#[cfg(feature = "std")]
+pub struct Transcriptor;
+
+#[cfg(feature = "std")]
+impl Transcriptor {
+    const TAGS_KEY: &str = "@tags:";
+    const LEVEL_KEY: &str = "@level:";
+
+    // finds all separators in the `Tablet`. Separators with no lines between them produce no segment
+    fn segmentation(tablet: &Tablet) -> Result<Vec<(usize, usize)>> {
+        let data = File::open(tablet.path())?;
+        let lines = BufReader::new(data)
+            .lines()
+            .skip(tablet.start())
+            .take(tablet.length())
+            .collect::<Result<Vec<String>>>()?;
+        Ok(parse::segments(&lines, tablet.start()))
+    }
+
+    /// reads the contents of [`Tablet`] or [`Shard`], formats it to match the `markdown` format, and returns as [`String`].
+    /// Paragraphs stay separated by a blank line, while longer runs of blank prose lines collapse into one
+    pub fn read(tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        Self::read_with(tablet, &ReadOptions::default())
+    }
+
+    /// reads the contents of [`Tablet`] or [`Shard`] like [`Transcriptor::read`], and returns them as UTF-8 bytes
+    pub fn read_bytes(tablet: &Tablet) -> std::result::Result<Vec<u8>, ReadError> {
+        Ok(Self::read(tablet)?.into_bytes())
+    }
+
+    /// reads the contents of [`Tablet`] or [`Shard`] like [`Transcriptor::read`], but keeps `@tags:` and `@level:` annotation lines
+    pub fn read_raw(tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        Self::read_with(tablet, &ReadOptions::new().strip_metadata(false))
+    }
+
+    /// reads the contents of [`Tablet`] or [`Shard`], formats it according to [`ReadOptions`], and returns as [`String`]
+    pub fn read_with(
+        tablet: &Tablet,
+        options: &ReadOptions,
+    ) -> std::result::Result<String, ReadError> {
+        let lines = Self::read_formatted(tablet, options.preserve_code_indent)?;
+        Ok(Self::render(lines, options))
+    }
+
+    // reads formatted lines of the `Tablet` like `formatted_iter_with`, pointing every failure at the line being read
+    fn read_formatted(
+        tablet: &Tablet,
+        preserve_code_indent: bool,
+    ) -> std::result::Result<Vec<String>, ReadError> {
+        Self::formatted_iter_with(tablet, preserve_code_indent)
+            .map_err(|err| ReadError::new(tablet, None, err))?
+            .enumerate()
+            .map(|(num, line)| {
+                line.map_err(|err| ReadError::new(tablet, Some(tablet.start() + num), err))
+            })
+            .collect()
+    }
+
+    /// reads the contents of [`Tablet`] or [`Shard`] like [`Transcriptor::read`], with error comments pulled out
+    /// above their code blocks as callouts. See [`ReadOptions::callouts`] for the recognized comments
+    pub fn read_with_callouts(tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        Self::read_with(tablet, &ReadOptions::new().callouts(true))
+    }
+
+    /// reads every [`Shard`] of the [`Tablet`] like [`Transcriptor::read`], in order, opening the note file only once
+    pub fn read_sections(tablet: &Tablet) -> std::result::Result<Vec<String>, ReadError> {
+        let lines = FsProvider
+            .read_lines(tablet.path_str())
+            .map_err(|err| ReadError::new(tablet, None, err))?;
+        let lines: Vec<String> = lines
+            .into_iter()
+            .skip(tablet.start())
+            .take(tablet.length())
+            .collect();
+        let options = ReadOptions::default();
+        Ok(parse::segments(&lines, 0)
+            .into_iter()
+            .map(|(start, end)| {
+                let mut in_code = false;
+                let section = lines[start..=end].iter().map(|line| {
+                    parse::format_line(line, &mut in_code, options.preserve_code_indent)
+                });
+                Self::render(section, &options)
+            })
+            .collect())
+    }
+
+    /// reads the contents of [`Tablet`] or [`Shard`] like [`Transcriptor::read`], with bare URLs moved into a footnote list.
+    /// See [`ReadOptions::footnotes`]
+    pub fn read_with_footnotes(tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        Self::read_with(tablet, &ReadOptions::new().footnotes(true))
+    }
+
+    /// reads the contents of [`Tablet`] or [`Shard`] like [`Transcriptor::read`], without the `rustdoc` hidden lines of code blocks.
+    /// See [`ReadOptions::hide_doctest_hidden_lines`]
+    pub fn read_stripped_doctests(tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        Self::read_with(tablet, &ReadOptions::new().hide_doctest_hidden_lines(true))
+    }
+
+    /// reads the contents of [`Tablet`] or [`Shard`] through the `provider`, and formats it like [`Transcriptor::read_with`]
+    pub fn read_from(
+        provider: &impl ContentProvider,
+        tablet: &Tablet,
+        options: &ReadOptions,
+    ) -> std::result::Result<String, ReadError> {
+        let mut in_code = false;
+        let lines = provider
+            .read_lines(tablet.path_str())
+            .map_err(|err| ReadError::new(tablet, None, err))?
+            .into_iter()
+            .skip(tablet.start())
+            .take(tablet.length())
+            .map(|line| parse::format_line(&line, &mut in_code, options.preserve_code_indent));
+        Ok(Self::render(lines, options))
+    }
+
+    /// reads the lines `start..=end` of any file at `path`, formats them like [`Transcriptor::read`], and returns as [`String`].
+    /// Fails with the [`ReadError`] of [`ErrorKind::InvalidInput`] kind if the range is reversed or exceeds the file length
+    pub fn read_range(
+        path: &Path,
+        start: usize,
+        end: usize,
+    ) -> std::result::Result<String, ReadError> {
+        let data = File::open(path).map_err(|err| ReadError::at(path, None, err))?;
+        let lines = BufReader::new(data)
+            .lines()
+            .enumerate()
+            .map(|(num, line)| line.map_err(|err| ReadError::at(path, Some(num), err)))
+            .collect::<std::result::Result<Vec<String>, ReadError>>()?;
+        if start > end || end >= lines.len() {
+            let message = format!(
+                "line range {start}..={end} is invalid for a file of {} lines",
+                lines.len()
+            );
+            let err = io::Error::new(ErrorKind::InvalidInput, message);
+            return Err(ReadError::at(path, None, err));
+        }
+        let mut in_code = false;
+        let formatted = lines[start..=end]
+            .iter()
+            .map(|line| parse::format_line(line, &mut in_code, true));
+        Ok(Self::render(formatted, &ReadOptions::default()))
+    }
+
+    /// reads the [`Tablet`] like [`Transcriptor::read`], and escapes it to be put between quotes of a `JSON` string.
+    /// Quotes, backslashes and control chars are escaped, and the surrounding quotes are not added
+    pub fn read_json_string(tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        let mut escaped = String::new();
+        for c in Self::read(tablet)?.chars() {
+            match c {
+                '"' => escaped.push_str("\\\""),
+                '\\' => escaped.push_str("\\\\"),
+                '\n' => escaped.push_str("\\n"),
+                '\r' => escaped.push_str("\\r"),
+                '\t' => escaped.push_str("\\t"),
+                c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+                c => escaped.push(c),
+            }
+        }
+        Ok(escaped)
+    }
+
+    /// reads the [`Tablet`] like [`Transcriptor::read`], but never fails: invalid UTF-8 sequences are replaced with `U+FFFD`,
+    /// and an unreadable file gives the content read so far. Use [`Transcriptor::read`] to validate the content
+    pub fn read_lossy(tablet: &Tablet) -> String {
+        let Ok(data) = File::open(tablet.path()) else {
+            return String::new();
+        };
+        let mut reader = BufReader::new(data);
+        let mut lines = Vec::new();
+        let mut buf: Vec<u8> = Vec::new();
+        let mut in_code = false;
+        for num in 0..=tablet.end() {
+            buf.clear();
+            match reader.read_until(b'\n', &mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(_) if num < tablet.start() => continue,
+                Ok(_) => {
+                    let line = String::from_utf8_lossy(&buf);
+                    let line = line.trim_end_matches(['\n', '\r']);
+                    lines.push(parse::format_line(line, &mut in_code, true));
+                }
+            }
+        }
+        Self::render(lines, &ReadOptions::default())
+    }
+
+    /// reads the lines `offset_start..=offset_end` of the [`Shard`] like [`Transcriptor::read`], where offsets are relative to the [`Shard`] start.
+    /// The range is clamped to the [`Shard`] bounds, and an empty range gives an empty [`String`]
+    pub fn read_lines_range(
+        shard: &Shard,
+        offset_start: usize,
+        offset_end: usize,
+    ) -> std::result::Result<String, ReadError> {
+        let start = shard.start().saturating_add(offset_start);
+        let end = shard.start().saturating_add(offset_end).min(shard.end());
+        if start > end {
+            return Ok(String::new());
+        }
+        Self::read(&Tablet(shard.path_str(), (start, end)))
+    }
+
+    /// reads the [`Tablet`] like [`Transcriptor::read`], but keeps at most `max_chars` chars,
+    /// appending `…` only if the text was truncated. Never cuts a multibyte [`char`] in the middle
+    pub fn read_truncated(
+        tablet: &Tablet,
+        max_chars: usize,
+    ) -> std::result::Result<String, ReadError> {
+        let contents = Self::read(tablet)?;
+        match contents.char_indices().nth(max_chars) {
+            Some((boundary, _)) => Ok(format!("{}…", &contents[..boundary])),
+            None => Ok(contents),
+        }
+    }
+
+    /// reads the [`Tablet`] in the `GitHub Flavored Markdown` format: code blocks are always tagged as `rust`,
+    /// separators are rendered as `---`, and paragraphs are separated by exactly one blank line
+    pub fn read_gfm(tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        let mut contents = String::new();
+        let mut in_code = false;
+        let mut blank = false;
+        for line in Self::read_formatted(tablet, true)? {
+            if !in_code && Self::is_metadata(&line) {
+                continue;
+            }
+            let line = match line.as_str() {
+                _ if parse::is_fence(&line) => {
+                    in_code = !in_code;
+                    if in_code && line == parse::FENCE {
+                        "```rust"
+                    } else {
+                        line.as_str()
+                    }
+                }
+                _ if in_code => line.as_str(),
+                _ if parse::is_formatted_separator(&line) => "---",
+                "" if blank => continue,
+                _ => line.as_str(),
+            };
+            blank = !in_code && line.is_empty();
+            contents.push_str(line);
+            contents.push('\n');
+        }
+        Ok(contents.trim().to_string())
+    }
+
+    /// reads the [`Tablet`] and renders it as HTML. Headings, paragraphs, separators and code blocks are supported.
+    /// Every heading gets the `id` anchor of [`Transcriptor::toc`], and every code block gets the `language-*` class of its fence, `rust` by default
+    pub fn read_html(tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        Self::html(Self::read_formatted(tablet, true)?, |lang, code| {
+            Ok(format!(
+                "<pre><code class=\"language-{lang}\">{}</code></pre>",
+                Self::html_escape(code)
+            ))
+        })
+    }
+
+    /// reads the [`Tablet`] like [`Transcriptor::read_html`], but highlights `rust` code blocks into inline styled `<span>`s.
+    /// Uses the light `InspiredGitHub` theme bundled with `syntect`, so the output needs no extra stylesheet
+    #[cfg(feature = "syntect")]
+    pub fn read_highlighted_html(tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        use std::sync::OnceLock;
+        use syntect::highlighting::ThemeSet;
+        use syntect::parsing::SyntaxSet;
+        static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+        static THEMES: OnceLock<ThemeSet> = OnceLock::new();
+        let syntaxes = SYNTAXES.get_or_init(SyntaxSet::load_defaults_newlines);
+        let theme = &THEMES.get_or_init(ThemeSet::load_defaults).themes["InspiredGitHub"];
+        Self::html(Self::read_formatted(tablet, true)?, |lang, code| {
+            let Some(syntax) = syntaxes
+                .find_syntax_by_token(lang)
+                .filter(|_| lang == "rust")
+            else {
+                return Ok(format!(
+                    "<pre><code class=\"language-{lang}\">{}</code></pre>",
+                    Self::html_escape(code)
+                ));
+            };
+            syntect::html::highlighted_html_for_string(
+                &format!("{code}\n"),
+                syntaxes,
+                syntax,
+                theme,
+            )
+            .map_err(|err| ReadError::new(tablet, None, io::Error::other(err)))
+        })
+    }
+
+    // renders formatted lines as HTML, turning every code block body into HTML with `code_block(lang, body)`.
+    // The `lang` of the fence is already escaped
+    fn html(
+        lines: Vec<String>,
+        code_block: impl Fn(&str, &str) -> std::result::Result<String, ReadError>,
+    ) -> std::result::Result<String, ReadError> {
+        let mut html = Vec::new();
+        let mut paragraph: Vec<String> = Vec::new();
+        let mut code: Option<(String, Vec<String>)> = None;
+        let mut slugs = Slugs::default();
+        let flush = |paragraph: &mut Vec<String>, html: &mut Vec<String>| {
+            if !paragraph.is_empty() {
+                html.push(format!("<p>{}</p>", paragraph.join("\n")));
+                paragraph.clear();
+            }
+        };
+        for line in lines {
+            if let Some((lang, body)) = code.as_mut() {
+                if parse::is_fence(&line) {
+                    html.push(code_block(lang, &body.join("\n"))?);
+                    code = None;
+                } else {
+                    body.push(line);
+                }
+                continue;
+            }
+            if Self::is_metadata(&line) {
+                continue;
+            }
+            if parse::is_fence(&line) {
+                flush(&mut paragraph, &mut html);
+                let lang = match parse::is_rust_fence(&line) {
+                    true => "rust",
+                    false => line[parse::FENCE.len()..]
+                        .split(',')
+                        .next()
+                        .unwrap_or("")
+                        .trim(),
+                };
+                code = Some((Self::html_escape(lang), Vec::new()));
+            } else if parse::is_formatted_separator(&line) {
+                flush(&mut paragraph, &mut html);
+                html.push("<hr>".to_string());
+            } else if let Some((level, text)) = parse::heading(&line) {
+                flush(&mut paragraph, &mut html);
+                html.push(format!(
+                    "<h{level} id=\"{}\">{}</h{level}>",
+                    slugs.next(text),
+                    Self::html_escape(text)
+                ));
+            } else if line.is_empty() {
+                flush(&mut paragraph, &mut html);
+            } else {
+                paragraph.push(Self::html_escape(&line));
+            }
+        }
+        flush(&mut paragraph, &mut html);
+        if let Some((lang, body)) = code {
+            html.push(code_block(&lang, &body.join("\n"))?);
+        }
+        Ok(html.join("\n"))
+    }
+
+    // escapes the HTML special characters of `text`
+    fn html_escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                _ => escaped.push(c),
+            }
+        }
+        escaped
+    }
+
+    /// reads the [`Tablet`] like [`Transcriptor::read`], but drops every `rust` code block, fences included.
+    /// An unclosed code block is dropped up to the end of the [`Tablet`]
+    pub fn read_without_code(tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        let prose = Self::prose(Self::read_formatted(tablet, true)?);
+        Ok(Self::render(prose, &ReadOptions::default()))
+    }
+
+    // reads formatted lines of the `Tablet` outside of `rust` code blocks
+    fn prose_lines(tablet: &Tablet) -> Result<Vec<String>> {
+        Ok(Self::prose(Self::formatted_lines(tablet)?))
+    }
+
+    // keeps only formatted lines outside of `rust` code blocks
+    fn prose(lines: Vec<String>) -> Vec<String> {
+        let mut prose = Vec::new();
+        let mut in_code = false;
+        let mut in_other = false;
+        for line in lines {
+            if in_code {
+                in_code = !parse::is_fence(&line);
+                continue;
+            }
+            if parse::is_fence(&line) {
+                if !in_other && parse::is_rust_fence(&line) {
+                    in_code = true;
+                    continue;
+                }
+                in_other = !in_other;
+            }
+            prose.push(line);
+        }
+        prose
+    }
+
+    // finds every intra-doc link text, like `String` in [`String`], in the formatted line
+    fn line_links(line: &str) -> impl Iterator<Item = &str> {
+        line.split("[`")
+            .skip(1)
+            .filter_map(|part| part.split_once("`]").map(|(link, _)| link))
+    }
+
+    /// reads the [`Tablet`] like [`Transcriptor::read`], but memory-maps the file and slices the range out by byte offsets.
+    /// Fails with the [`ReadError`] of [`ErrorKind::InvalidData`] kind, at the first invalid line, if the range is not valid UTF-8
+    #[cfg(feature = "mmap")]
+    pub fn read_mmap(tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        let file = File::open(tablet.path()).map_err(|err| ReadError::new(tablet, None, err))?;
+        // SAFETY: the map is read only and dropped before returning. Notes are not expected to be modified while being read
+        let map = unsafe { memmap2::Mmap::map(&file) }
+            .map_err(|err| ReadError::new(tablet, None, err))?;
+        let mut breaks = map
+            .iter()
+            .enumerate()
+            .filter(|&(_, &byte)| byte == b'\n')
+            .map(|(offset, _)| offset + 1);
+        let start = match tablet.start() {
+            0 => 0,
+            line => breaks.nth(line - 1).unwrap_or(map.len()),
+        };
+        let end = breaks.nth(tablet.length() - 1).unwrap_or(map.len());
+        let text = std::str::from_utf8(&map[start..end]).map_err(|err| {
+            let valid = &map[start..start + err.valid_up_to()];
+            let line = tablet.start() + valid.iter().filter(|&&byte| byte == b'\n').count();
+            ReadError::new(
+                tablet,
+                Some(line),
+                io::Error::new(ErrorKind::InvalidData, err),
+            )
+        })?;
+        let mut in_code = false;
+        let formatted = text
+            .lines()
+            .map(|line| parse::format_line(line, &mut in_code, true));
+        Ok(Self::render(formatted, &ReadOptions::default()))
+    }
+
+    /// reads the [`Tablet`] like [`Transcriptor::read`], but adds `shift` levels to every heading,
+    /// so `# ` becomes `### ` for the `shift` of 2. Levels are clamped to 6, and code blocks are left untouched
+    pub fn read_shifted(tablet: &Tablet, shift: usize) -> std::result::Result<String, ReadError> {
+        let lines = Self::read_formatted(tablet, true)?;
+        let mask = parse::code_mask(&lines);
+        let shifted = lines.iter().zip(mask).map(|(line, in_code)| {
+            match parse::heading(line).filter(|_| !in_code) {
+                Some((level, text)) => {
+                    let level = level.saturating_add(shift).min(6);
+                    format!("{} {text}", "#".repeat(level))
+                        .trim_end()
+                        .to_string()
+                }
+                None => line.clone(),
+            }
+        });
+        Ok(Self::render(shifted, &ReadOptions::default()))
+    }
+
+    /// reads the [`Tablet`] like [`Transcriptor::read`], but wraps prose lines to `width` chars between words.
+    /// Code blocks, headings, separators and annotation lines are never wrapped, and a word longer than `width` stays on its own line
+    pub fn read_wrapped(tablet: &Tablet, width: usize) -> std::result::Result<String, ReadError> {
+        let lines = Self::read_formatted(tablet, true)?;
+        let mask = parse::code_mask(&lines);
+        let mut wrapped = Vec::new();
+        for (line, in_code) in lines.into_iter().zip(mask) {
+            if in_code
+                || parse::heading(&line).is_some()
+                || Self::is_metadata(&line)
+                || parse::is_formatted_separator(&line)
+                || line.chars().count() <= width
+            {
+                wrapped.push(line);
+                continue;
+            }
+            let mut current = String::new();
+            for word in line.split_whitespace() {
+                let len = current.chars().count();
+                if len > 0 && len + 1 + word.chars().count() > width {
+                    wrapped.push(std::mem::take(&mut current));
+                }
+                if !current.is_empty() {
+                    current.push(' ');
+                }
+                current.push_str(word);
+            }
+            wrapped.push(current);
+        }
+        Ok(Self::render(wrapped, &ReadOptions::default()))
+    }
+
+    /// builds the `markdown` table of contents of the [`Tablet`]: a bullet list of links to every heading,
+    /// indented by the heading depth. Anchors follow [`Transcriptor::slug`]. Empty when there are no headings
+    pub fn toc(tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        let outline = Self::headings(&Self::read_formatted(tablet, true)?);
+        let top = outline.iter().map(|&(level, _)| level).min().unwrap_or(1);
+        let mut slugs = Slugs::default();
+        Ok(outline
+            .iter()
+            .map(|(level, text)| {
+                let indent = "  ".repeat(level - top);
+                format!("{indent}- [{text}](#{})", slugs.next(text))
+            })
+            .collect::<Vec<String>>()
+            .join("\n"))
+    }
+
+    /// converts the heading `text` into the anchor slug, like `GitHub` does: lowercase alphanumerics, `-` and `_` are kept,
+    /// spaces become `-`, everything else is dropped
+    pub fn slug(text: &str) -> String {
+        text.trim()
+            .to_lowercase()
+            .chars()
+            .filter_map(|c| match c {
+                ' ' => Some('-'),
+                c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // joins formatted lines into the `markdown` text according to `ReadOptions`
+    fn render(lines: impl IntoIterator<Item = String>, options: &ReadOptions) -> String {
+        let mut contents = String::new();
+        let mut code: Option<(Vec<String>, Vec<String>)> = None;
+        let mut in_code = false;
+        let mut urls = Vec::new();
+        let mut fence: Option<bool> = None;
+        let mut blanks = 0;
+        for line in lines {
+            if parse::is_fence(&line) {
+                fence = match fence {
+                    Some(_) => None,
+                    None => Some(parse::is_rust_fence(&line)),
+                };
+            } else if options.hide_doctest_hidden_lines
+                && fence == Some(true)
+                && Self::is_hidden(&line)
+            {
+                continue;
+            }
+            if let Some((block, callouts)) = code.as_mut().filter(|_| options.callouts) {
+                if let Some(callout) = Self::callout(&line) {
+                    callouts.push(callout.to_string());
+                }
+                let closed = parse::is_fence(&line);
+                block.push(line);
+                if closed {
+                    Self::push_callouts(&mut contents, code.take());
+                }
+                continue;
+            }
+            if options.strip_metadata && fence.is_none() && Self::is_metadata(&line) {
+                continue;
+            }
+            if options.callouts && parse::is_fence(&line) {
+                code = Some((vec![line], Vec::new()));
+                continue;
+            }
+            let line = match parse::is_fence(&line) {
+                true => {
+                    in_code = !in_code;
+                    line
+                }
+                false if in_code => line,
+                false => {
+                    let line = match options.normalize_headings {
+                        true => Self::spaced_heading(line),
+                        false => line,
+                    };
+                    match options.footnotes {
+                        true => Self::footnote_urls(&line, &mut urls),
+                        false => line,
+                    }
+                }
+            };
+            blanks = match !in_code && line.is_empty() {
+                true => blanks + 1,
+                false => 0,
+            };
+            if blanks > options.max_consecutive_blank_lines {
+                continue;
+            }
+            contents.push_str(line.as_str());
+            contents.push('\n');
+        }
+        Self::push_callouts(&mut contents, code);
+        let mut contents = contents.trim().to_string();
+        for (index, url) in urls.iter().enumerate() {
+            let gap = if index == 0 { "\n\n" } else { "\n" };
+            contents.push_str(&format!("{gap}[^{}]: {url}", index + 1));
+        }
+        contents
+    }
+
+    // checks if the code line is hidden from the `rustdoc` output: `#` alone or followed by a space, after the indentation
+    fn is_hidden(line: &str) -> bool {
+        let line = line.trim_start();
+        line == "#" || line.starts_with("# ")
+    }
+
+    // inserts the missing space after the leading `#`s of the prose line, so `#Heading` becomes `# Heading`.
+    // Lines like `#[attr]` or `#!` are kept
+    fn spaced_heading(line: String) -> String {
+        let level = line.chars().take_while(|&c| c == '#').count();
+        match line[level..].chars().next() {
+            Some(c) if (1..=6).contains(&level) && !c.is_whitespace() && c != '[' && c != '!' => {
+                format!("{} {}", &line[..level], &line[level..])
+            }
+            _ => line,
+        }
+    }
+
+    // replaces every bare `http(s)://` URL of the prose line with its `[^n]` footnote reference, numbering new URLs after `urls`.
+    // URLs which are already link targets, like `](url)` or `<url>`, are kept
+    fn footnote_urls(line: &str, urls: &mut Vec<String>) -> String {
+        let mut replaced = String::new();
+        let mut rest = line;
+        while let Some(position) = ["https://", "http://"]
+            .iter()
+            .filter_map(|scheme| rest.find(scheme))
+            .min()
+        {
+            replaced.push_str(&rest[..position]);
+            let tail = &rest[position..];
+            let length = tail
+                .find(|c: char| {
+                    c.is_whitespace() || matches!(c, '<' | '>' | '"' | '\'' | ')' | ']')
+                })
+                .unwrap_or(tail.len());
+            let url = tail[..length].trim_end_matches(['.', ',', ';', ':', '!', '?']);
+            if replaced.ends_with("](") || replaced.ends_with('<') {
+                replaced.push_str(url);
+            } else {
+                let number = match urls.iter().position(|known| known == url) {
+                    Some(index) => index + 1,
+                    None => {
+                        urls.push(url.to_string());
+                        urls.len()
+                    }
+                };
+                replaced.push_str(&format!("[^{number}]"));
+            }
+            rest = &tail[url.len()..];
+        }
+        replaced.push_str(rest);
+        replaced
+    }
+
+    // appends the code block lines, preceded by its callouts as `markdown` blockquotes
+    fn push_callouts(contents: &mut String, code: Option<(Vec<String>, Vec<String>)>) {
+        let Some((block, callouts)) = code else {
+            return;
+        };
+        for callout in &callouts {
+            contents.push_str(&format!("> ⚠️ {callout}\n"));
+        }
+        if !callouts.is_empty() {
+            contents.push('\n');
+        }
+        for line in block {
+            contents.push_str(&line);
+            contents.push('\n');
+        }
+    }
+
+    // returns the text of a `// Error...` or `// PROBLEM...` comment inside code, matched ignoring case.
+    // The comment may fill the whole line or trail the code, like `let r2 = r1; // PROBLEM`. A `//` counts only
+    // at the line start or after whitespace, and outside of string literals, so URLs like `"http://..."` are skipped
+    fn callout(line: &str) -> Option<&str> {
+        let mut in_string = false;
+        let mut escaped = false;
+        let mut previous = ' ';
+        for (position, c) in line.char_indices() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' if in_string => escaped = true,
+                '"' => in_string = !in_string,
+                '/' if !in_string
+                    && previous.is_whitespace()
+                    && line[position..].starts_with("//") =>
+                {
+                    let text = line[position + 2..].trim();
+                    let upper = text.to_uppercase();
+                    if upper.starts_with("ERROR") || upper.starts_with("PROBLEM") {
+                        return Some(text);
+                    }
+                }
+                _ => {}
+            }
+            previous = c;
+        }
+        None
+    }
+
+    // every word of formatted lines outside of code blocks and annotation lines, skipping markers like `#` or `-`
+    fn prose_words(lines: &[String]) -> impl Iterator<Item = &str> {
+        lines
+            .iter()
+            .zip(parse::code_mask(lines))
+            .filter(|(line, in_code)| !in_code && !Self::is_metadata(line))
+            .flat_map(|(line, _)| line.split_whitespace())
+            .filter(|word| word.chars().any(char::is_alphanumeric))
+    }
+
+    // `(level, text)` of every heading of formatted lines, outside of code blocks
+    fn headings(lines: &[String]) -> Vec<(usize, String)> {
+        lines
+            .iter()
+            .zip(parse::code_mask(lines))
+            .filter(|&(_, in_code)| !in_code)
+            .filter_map(|(line, _)| parse::heading(line))
+            .map(|(level, text)| (level, text.to_string()))
+            .collect()
+    }
+
+    // time to read `words` at `wpm` words per minute
+    fn reading_time(words: usize, wpm: usize) -> Duration {
+        Duration::from_secs_f64(words as f64 * 60.0 / wpm.max(1) as f64)
+    }
+
+    // finds the text of the first `# ` heading in formatted lines, outside of code blocks
+    fn title(lines: &[String]) -> Option<String> {
+        lines
+            .iter()
+            .zip(parse::code_mask(lines))
+            .filter(|&(_, in_code)| !in_code)
+            .find_map(|(line, _)| {
+                line.strip_prefix("# ")
+                    .map(|title| title.trim().to_string())
+                    .filter(|title| !title.is_empty())
+            })
+    }
+
+    // checks if the formatted line is an annotation line, like `@tags:` or `@level:`
+    fn is_metadata(line: &str) -> bool {
+        line.starts_with(Self::TAGS_KEY) || line.starts_with(Self::LEVEL_KEY)
+    }
+
+    // reads every line of the `Tablet` formatted to match `markdown` format, without line breaks
+    fn formatted_lines(tablet: &Tablet) -> Result<Vec<String>> {
+        Self::formatted_iter(tablet)?.collect()
+    }
+
+    // lazily reads lines of the `Tablet` formatted to match `markdown` format, without line breaks
+    fn formatted_iter(tablet: &Tablet) -> Result<impl Iterator<Item = Result<String>>> {
+        Self::formatted_iter_with(tablet, true)
+    }
+
+    // lazily reads formatted lines of the `Tablet`, keeping indentation inside code blocks if `preserve_code_indent` is set
+    fn formatted_iter_with(
+        tablet: &Tablet,
+        preserve_code_indent: bool,
+    ) -> Result<impl Iterator<Item = Result<String>>> {
+        let data = File::open(tablet.path())?;
+        Ok(BufReader::new(data)
+            .lines()
+            .skip(tablet.start())
+            .take(tablet.length())
+            .scan(false, move |in_code, line| {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(err) => return Some(Err(err)),
+                };
+                Some(Ok(parse::format_line(&line, in_code, preserve_code_indent)))
+            }))
+    }
+
+    // reads formatted lines until the next complete `rust` code block, and returns its body
+    fn next_code_block(lines: &mut impl Iterator<Item = Result<String>>) -> Result<Option<String>> {
+        let mut body: Option<Vec<String>> = None;
+        let mut in_other = false;
+        for line in lines {
+            let line = line?;
+            match body.as_mut() {
+                Some(block) if parse::is_fence(&line) => return Ok(Some(block.join("\n"))),
+                Some(block) => block.push(line),
+                None if parse::is_fence(&line) && in_other => in_other = false,
+                None if parse::is_rust_fence(&line) => body = Some(Vec::new()),
+                None if parse::is_fence(&line) => in_other = true,
+                None => {}
+            }
+        }
+        Ok(None)
+    }
+
+    // finds the value of the first `@key:` annotation line in the `Tablet`, outside of code blocks
+    fn metadata(tablet: &Tablet, key: &str) -> Result<Option<String>> {
+        let lines = Self::formatted_lines(tablet)?;
+        Ok(lines
+            .iter()
+            .zip(parse::code_mask(&lines))
+            .filter(|&(_, in_code)| !in_code)
+            .find_map(|(line, _)| {
+                line.strip_prefix(key)
+                    .map(|value| value.trim().to_string())
+                    .filter(|value| !value.is_empty())
+            }))
+    }
+
+    // escapes the string to be used as a double quoted `YAML` value
+    fn yaml_str(value: &str) -> String {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
+    /// reads the [`Tablet`] like [`Transcriptor::read`], but prepends `YAML` frontmatter used by static site generators.
+    /// Contains `title`, and also `level` and `tags` when the note declares them
+    pub fn read_with_frontmatter(tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        let metadata = |err| ReadError::new(tablet, None, err);
+        let title = tablet.display_name().map_err(metadata)?;
+        let mut frontmatter = format!("---\ntitle: {}\n", Self::yaml_str(&title));
+        if let Some(level) = tablet.level().map_err(metadata)? {
+            frontmatter.push_str(&format!("level: {}\n", Self::yaml_str(&level)));
+        }
+        let tags = tablet.tags().map_err(metadata)?;
+        if !tags.is_empty() {
+            let tags: Vec<String> = tags.iter().map(|tag| Self::yaml_str(tag)).collect();
+            frontmatter.push_str(&format!("tags: [{}]\n", tags.join(", ")));
+        }
+        frontmatter.push_str("---\n");
+        Ok(frontmatter + Self::read(tablet)?.as_str())
+    }
+}
+
+/// `ReadOptions` configures how [`Transcriptor::read_with`] renders [`Tablet`]s and [`Shard`]s
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadOptions {
+    strip_metadata: bool,
+    preserve_code_indent: bool,
+    callouts: bool,
+    footnotes: bool,
+    normalize_headings: bool,
+    hide_doctest_hidden_lines: bool,
+    max_consecutive_blank_lines: usize,
+}
+
+impl ReadOptions {
+    /// default options, same as used by [`Transcriptor::read`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// whether `@tags:` and `@level:` annotation lines are removed from the output. Enabled by default
+    pub fn strip_metadata(mut self, strip: bool) -> Self {
+        self.strip_metadata = strip;
+        self
+    }
+
+    /// whether lines inside code blocks keep their indentation, while prose lines are still trimmed. Enabled by default
+    pub fn preserve_code_indent(mut self, preserve: bool) -> Self {
+        self.preserve_code_indent = preserve;
+        self
+    }
+
+    /// whether `// Error...` and `// PROBLEM...` comments inside code blocks, matched ignoring case and also when they
+    /// trail the code line, are also rendered as `> ⚠️` blockquotes right above their code block. Disabled by default
+    pub fn callouts(mut self, callouts: bool) -> Self {
+        self.callouts = callouts;
+        self
+    }
+
+    /// whether bare `http://` and `https://` URLs of the prose are replaced with GFM `[^n]` footnote references, listed at the bottom
+    /// as `[^n]: url`. URLs inside code blocks and existing link targets are kept. Disabled by default
+    pub fn footnotes(mut self, footnotes: bool) -> Self {
+        self.footnotes = footnotes;
+        self
+    }
+
+    /// whether a missing space after the leading `#`s of a prose line is inserted, so `#Heading` becomes `# Heading`.
+    /// Lines inside code blocks and lines like `#[attr]` are kept. Disabled by default
+    pub fn normalize_headings(mut self, normalize: bool) -> Self {
+        self.normalize_headings = normalize;
+        self
+    }
+
+    /// whether `rustdoc` hidden lines, `#` alone or followed by a space, are removed from `rust` code blocks,
+    /// like the documentation shows them. Other code blocks are kept as is. Disabled by default
+    pub fn hide_doctest_hidden_lines(mut self, hide: bool) -> Self {
+        self.hide_doctest_hidden_lines = hide;
+        self
+    }
+
+    /// how many blank prose lines in a row are kept, collapsing longer runs. Blank lines inside code blocks are always kept.
+    /// Defaults to 1, which keeps paragraphs separated. Use [`usize::MAX`] to keep every blank line
+    pub fn max_consecutive_blank_lines(mut self, max: usize) -> Self {
+        self.max_consecutive_blank_lines = max;
+        self
+    }
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        ReadOptions {
+            strip_metadata: true,
+            preserve_code_indent: true,
+            callouts: false,
+            footnotes: false,
+            normalize_headings: false,
+            hide_doctest_hidden_lines: false,
+            max_consecutive_blank_lines: 1,
+        }
+    }
+}
+
+// generates unique heading slugs, suffixing repeated ones with `-1`, `-2`, and so on
+#[cfg(feature = "std")]
+#[derive(Default)]
+struct Slugs(HashMap<String, usize>);
+
+#[cfg(feature = "std")]
+impl Slugs {
+    fn next(&mut self, text: &str) -> String {
+        let slug = Transcriptor::slug(text);
+        let seen = self.0.entry(slug.clone()).or_insert(0);
+        *seen += 1;
+        match *seen {
+            1 => slug,
+            n => format!("{slug}-{}", n - 1),
+        }
+    }
+}
+
+/// `Shards` is an iterator over every [`Shard`] from the [`Tablet`]
+#[derive(Debug, Clone)]
+pub struct Shards {
+    origin: Tablet,
+    segments: IntoIter<(usize, usize)>,
+}
+
+impl Iterator for Shards {
+    type Item = Shard;
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(Tablet(self.origin.path_str(), self.segments.next()?))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        Some(Tablet(self.origin.path_str(), self.segments.nth(n)?))
+    }
+}
+
+impl FusedIterator for Shards {}
+
+impl Shards {
+    /// splits the `tablet` into [`Shard`]s over its source `lines`, which are the lines `start..=end` of the note,
+    /// without touching the file. Works without `std`, for notes read by other means
+    pub fn from_lines<I>(tablet: Tablet, lines: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        Shards {
+            origin: tablet,
+            segments: parse::segments(lines, tablet.start()).into_iter(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Tablet> for Shards {
+    fn from(tablet: Tablet) -> Self {
+        let segments = match Registry::cached(tablet.path_str()) {
+            Some((whole, shards)) if whole == tablet => {
+                shards.iter().map(|shard| shard.1).collect()
+            }
+            _ => Transcriptor::segmentation(&tablet).expect(TABLET_UNREADABLE_MSG),
+        };
+        Shards {
+            origin: tablet,
+            segments: segments.into_iter(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<&Tablet> for Shards {
+    fn from(tablet: &Tablet) -> Self {
+        Self::from(*tablet)
+    }
+}
+
+/// `Registry` represents a collection of all [`Tablet`]s and [`Shard`]s available
+#[cfg(feature = "std")]
+pub struct Registry;
+
+// every bundled note as its whole `Tablet` and `Shard`s, shared between readers of the `Registry`
+#[cfg(feature = "std")]
+type Snapshot = Arc<Vec<(Tablet, Vec<Shard>)>>;
+
+/// `RegistryStats` aggregates counts over all [`Tablet`]s of the [`Registry`]. Returned by [`Registry::stats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RegistryStats {
+    /// count of [`Tablet`]s
+    pub tablets: usize,
+    /// count of [`Shard`]s
+    pub shards: usize,
+    /// count of source lines
+    pub lines: usize,
+    /// count of [`Tablet::words`]
+    pub words: usize,
+    /// count of complete `rust` code blocks
+    pub code_blocks: usize,
+}
+
+/// `RegistryBuilder` collects note file paths to build the [`CustomRegistry`] over your own notes
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct RegistryBuilder {
+    paths: Vec<&'static str>,
+}
+
+#[cfg(feature = "std")]
+impl RegistryBuilder {
+    /// empty builder without any notes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// adds the note file located at `path`
+    pub fn add_path(mut self, path: &'static str) -> Self {
+        self.paths.push(path);
+        self
+    }
+
+    /// adds every note file from `paths`, in order
+    pub fn add_paths(mut self, paths: impl IntoIterator<Item = &'static str>) -> Self {
+        self.paths.extend(paths);
+        self
+    }
+
+    /// builds the [`CustomRegistry`] over all added notes, splitting each of them into [`Shard`]s right away.
+    /// Fails if any note is unreadable or empty, so the built registry never panics on a bad path
+    pub fn build(self) -> Result<CustomRegistry> {
+        self.build_with(FsProvider)
+    }
+
+    /// builds the [`CustomRegistry`] over all added notes like [`RegistryBuilder::build`], reading them through the `provider`
+    pub fn build_with<P: ContentProvider>(self, provider: P) -> Result<CustomRegistry<P>> {
+        let notes = self
+            .paths
+            .iter()
+            .map(|&path| {
+                let lines = provider
+                    .read_lines(path)
+                    .map_err(|err| ReadError::at(Path::new(path), None, err))?;
+                Ok(Registry::split_lines(path, &lines)?)
+            })
+            .collect::<Result<Vec<(Tablet, Vec<Shard>)>>>()?;
+        Ok(CustomRegistry {
+            paths: self.paths,
+            notes,
+            provider,
+        })
+    }
+}
+
+/// `CustomRegistry` represents a collection of [`Tablet`]s and [`Shard`]s built from the user supplied paths with [`RegistryBuilder`]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct CustomRegistry<P = FsProvider> {
+    paths: Vec<&'static str>,
+    notes: Vec<(Tablet, Vec<Shard>)>,
+    provider: P,
+}
+
+#[cfg(feature = "std")]
+impl<P: ContentProvider> CustomRegistry<P> {
+    /// paths of all notes, in order they were added
+    pub fn paths(&self) -> &[&'static str] {
+        &self.paths
+    }
+
+    /// returns all [`Tablet`]s in the form of [`Vec`]. Use [`Transcriptor`] to read from the [`Tablet`]
+    pub fn catalog(&self) -> Vec<Tablet> {
+        self.notes.iter().map(|&(tablet, _)| tablet).collect()
+    }
+
+    /// returns all [`Shard`]s in the form of [`Vec`]. Use [`Transcriptor`] to read from the [`Shard`]
+    pub fn heap(&self) -> Vec<Shard> {
+        self.notes
+            .iter()
+            .flat_map(|(_, shards)| shards.iter().copied())
+            .collect()
+    }
+
+    /// returns every [`Shard`] whose formatted text contains `query`, ignoring case
+    pub fn search(&self, query: &str) -> Result<Vec<Shard>> {
+        let query = query.to_lowercase();
+        let mut found = Vec::new();
+        for shard in self.heap() {
+            let contents =
+                Transcriptor::read_from(&self.provider, &shard, &ReadOptions::default())?;
+            if contents.to_lowercase().contains(&query) {
+                found.push(shard);
+            }
+        }
+        Ok(found)
+    }
+
+    /// reads the [`Tablet`] or [`Shard`] through the provider, like [`Transcriptor::read`]
+    pub fn read(&self, tablet: &Tablet) -> std::result::Result<String, ReadError> {
+        Transcriptor::read_from(&self.provider, tablet, &ReadOptions::default())
+    }
+
+    /// returns [`Shards`] iterator over every [`Shard`] in the [`Tablet`], like [`Tablet::shards`], splitting the lines of the provider
+    pub fn shards(&self, tablet: &Tablet) -> Result<Shards> {
+        Ok(Shards::from_lines(*tablet, self.lines(tablet)?))
+    }
+
+    /// text of the first `# ` heading in the [`Tablet`], like [`Tablet::title`], read through the provider
+    pub fn title(&self, tablet: &Tablet) -> Result<Option<String>> {
+        Ok(Transcriptor::title(&parse::format_all(
+            &self.lines(tablet)?,
+        )))
+    }
+
+    // source lines of the `Tablet` range through the provider
+    fn lines(&self, tablet: &Tablet) -> Result<Vec<String>> {
+        Ok(self
+            .provider
+            .read_lines(tablet.path_str())?
+            .into_iter()
+            .skip(tablet.start())
+            .take(tablet.length())
+            .collect())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Registry {
+    // every bundled `Tablet` with its `Shard`s, built on the first call and then shared until the next `Registry::reload` replaces it
+    fn snapshot() -> Snapshot {
+        Self::try_snapshot().expect(TABLET_UNREADABLE_MSG)
+    }
+
+    // same as `Registry::snapshot`, but returns the error of the first unreadable note instead of panicking
+    fn try_snapshot() -> std::result::Result<Snapshot, TabletError> {
+        if let Some(snapshot) = Self::cache()
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+        {
+            return Ok(Arc::clone(snapshot));
+        }
+        let fresh = Self::split_all()?;
+        let mut cache = Self::cache()
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        Ok(Arc::clone(cache.get_or_insert(fresh)))
+    }
+
+    // splits every bundled note into its whole `Tablet` and `Shard`s, reading each note file only once
+    fn split_all() -> std::result::Result<Snapshot, TabletError> {
+        let notes = registry::TABLETS
+            .iter()
+            .map(|&path| Self::split(path))
+            .collect::<std::result::Result<Vec<(Tablet, Vec<Shard>)>, TabletError>>()?;
+        Ok(Arc::new(notes))
+    }
+
+    // whole `Tablet` of the bundled note at `path`, from the `Registry::snapshot` if it is already built.
+    // Otherwise only this note file is opened to count its lines
+    fn tablet(path: &'static str) -> std::result::Result<Tablet, TabletError> {
+        match Self::cached(path) {
+            Some((tablet, _)) => Ok(tablet),
+            None => Tablet::from_path(path),
+        }
+    }
+
+    // whole `Tablet` and `Shard`s of the bundled note at `path` from the `Registry::snapshot`, if it is already built
+    fn cached(path: &str) -> Option<(Tablet, Vec<Shard>)> {
+        Self::cache()
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()?
+            .iter()
+            .find(|(tablet, _)| tablet.path_str() == path)
+            .cloned()
+    }
+
+    // storage of the `Registry::snapshot`
+    fn cache() -> &'static RwLock<Option<Snapshot>> {
+        static CACHE: RwLock<Option<Snapshot>> = RwLock::new(None);
+        &CACHE
+    }
+
+    /// re-splits every bundled note and swaps the cached [`Tablet`]s and [`Shard`]s all at once, so concurrent readers
+    /// see either the old or the new [`Registry::catalog`] and [`Registry::heap`], never a mix. Returns the new catalog.
+    /// If any note is unreadable, the error is returned and the old catalog is kept
+    pub fn reload() -> Result<Vec<Tablet>> {
+        let fresh = Self::split_all()?;
+        let tablets = fresh.iter().map(|&(tablet, _)| tablet).collect();
+        *Self::cache()
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(fresh);
+        Ok(tablets)
+    }
+
+    /// returns the first [`Tablet`] whose name matches `name`, ignoring ASCII case, or `None` if there is no such [`Tablet`]
+    pub fn find_by_name(name: &str) -> Option<Tablet> {
+        Self::catalog()
+            .into_iter()
+            .find(|tablet| tablet.name_matches(name))
+    }
+
+    /// returns every [`Tablet`] whose name matches `name`, ignoring ASCII case, in the [`Registry::catalog`] order.
+    /// More than one [`Tablet`] is found when notes in different directories share a file name
+    pub fn find_all_by_name(name: &str) -> Vec<Tablet> {
+        Self::catalog()
+            .into_iter()
+            .filter(|tablet| tablet.name_matches(name))
+            .collect()
+    }
+
+    /// returns every [`Tablet`] whose [`Tablet::path_str`] starts with `prefix`, in the [`Registry::catalog`] order.
+    /// The bundled paths are absolute, so `prefix` may also be relative to the crate root, like `"src/registry/"`.
+    /// Filtering uses the static paths only, and just the matching note files are opened
+    pub fn by_path_prefix(prefix: &str) -> Vec<Tablet> {
+        let root = concat!(env!("CARGO_MANIFEST_DIR"), "/");
+        registry::TABLETS
+            .iter()
+            .filter(|&&path| {
+                path.starts_with(prefix)
+                    || path
+                        .strip_prefix(root)
+                        .is_some_and(|relative| relative.starts_with(prefix))
+            })
+            .map(|&path| Self::tablet(path).expect(TABLET_UNREADABLE_MSG))
+            .collect()
+    }
+
+    /// returns names of all available [`Tablet`]s, in the [`Registry::catalog`] order. Derived from the paths only, without any I/O
+    pub fn catalog_names() -> Vec<&'static str> {
+        registry::TABLETS
+            .iter()
+            .map(|&path| Tablet::stem(path))
+            .collect()
+    }
+
+    /// returns paths of all bundled notes, in the [`Registry::catalog`] order. Known at compile time, so no file is opened
+    pub fn paths() -> &'static [&'static str] {
+        &registry::TABLETS
+    }
+
+    /// builds the [`CustomRegistry`] over the bundled notes, reading them through the `provider` instead of the filesystem,
+    /// like an [`InMemoryProvider`] serving the notes embedded into a WASM binary. The [`Registry::paths`] are used as the note keys
+    pub fn with_provider<P: ContentProvider>(provider: P) -> Result<CustomRegistry<P>> {
+        RegistryBuilder::new()
+            .add_paths(registry::TABLETS.iter().copied())
+            .build_with(provider)
+    }
+
+    // builds the `CustomRegistry` over the bundled notes of the `Registry::snapshot`
+    fn bundled() -> CustomRegistry {
+        CustomRegistry {
+            paths: registry::TABLETS.to_vec(),
+            notes: Self::snapshot().to_vec(),
+            provider: FsProvider,
+        }
+    }
+
+    /// returns all available [`Tablet`]s in the form of [`Vec`]. Use [`Transcriptor`] to read from the [`Tablet`].
+    /// Every note is split on the first call and cached along with its [`Shard`]s, use [`Registry::reload`] to pick up edited notes
+    pub fn catalog() -> Vec<Tablet> {
+        Self::snapshot().iter().map(|&(tablet, _)| tablet).collect()
+    }
+
+    /// returns all available [`Tablet`]s as a parallel iterator. The iterator is indexed,
+    /// so `collect` into a [`Vec`] keeps the [`Registry::catalog`] order
+    #[cfg(feature = "rayon")]
+    pub fn par_catalog() -> impl rayon::iter::IndexedParallelIterator<Item = Tablet> {
+        use rayon::iter::IntoParallelIterator;
+        Self::catalog().into_par_iter()
+    }
+
+    /// returns all available [`Tablet`]s without content duplicates, keeping the first one in the [`Registry::catalog`] order.
+    /// Every [`Tablet`] is fully read and its `markdown` contents are compared as a whole
+    pub fn catalog_dedup() -> Result<Vec<Tablet>> {
+        Self::dedup(Self::catalog())
+    }
+
+    // keeps the first of every group of `tablets` with equal `markdown` contents, in order
+    fn dedup(tablets: impl IntoIterator<Item = Tablet>) -> Result<Vec<Tablet>> {
+        let mut seen = HashSet::new();
+        let mut unique = Vec::new();
+        for tablet in tablets {
+            if seen.insert(Transcriptor::read(&tablet)?) {
+                unique.push(tablet);
+            }
+        }
+        Ok(unique)
+    }
+
+    /// returns a lazy iterator over every available [`Tablet`], in the [`Registry::catalog`] order. Tablets already cached
+    /// by [`Registry::catalog`] are served from memory, other note files are opened only when their item is requested.
+    /// Unlike [`Registry::catalog`] it never panics: a broken note file yields its error in place, and the iteration goes on
+    pub fn try_iter() -> impl Iterator<Item = Result<Tablet>> {
+        registry::TABLETS
+            .iter()
+            .map(|&path| Self::tablet(path).map_err(io::Error::from))
+    }
+
+    /// returns a lazy iterator over `(name, markdown)` of every [`Registry::try_iter`] item, read by [`Transcriptor::read`].
+    /// Every note file is opened only when its item is requested, so stopping early skips the rest, and its I/O error is returned in place
+    pub fn rendered() -> impl Iterator<Item = Result<(String, String)>> {
+        Self::try_iter().map(|tablet| {
+            let tablet = tablet?;
+            Ok((tablet.name().to_string(), Transcriptor::read(&tablet)?))
+        })
+    }
+
+    /// returns all available [`Tablet`]s in the form of [`HashMap`] keyed by [`Tablet::name`].
+    /// If several notes share the same name, the last one in the [`Registry::catalog`] order wins
+    pub fn catalog_map() -> HashMap<String, Tablet> {
+        Self::catalog()
+            .into_iter()
+            .map(|tablet| (tablet.name().to_string(), tablet))
+            .collect()
+    }
+
+    /// returns all available [`Tablet`]s grouped by the parent directory of their [`Tablet::path`], in the [`Registry::catalog`] order
+    /// within each group. Top-level files are keyed by the empty string. Grouping uses the paths only, not the contents
+    pub fn group_by_directory() -> BTreeMap<String, Vec<Tablet>> {
+        let mut groups: BTreeMap<String, Vec<Tablet>> = BTreeMap::new();
+        for tablet in Self::catalog() {
+            let directory = tablet
+                .path()
+                .parent()
+                .map(|parent| parent.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            groups.entry(directory).or_default().push(tablet);
+        }
+        groups
+    }
+
+    /// returns the count of available [`Tablet`]s. The bundled notes are known at compile time, so no file is opened
+    pub fn catalog_count_cached() -> usize {
+        registry::TABLETS.len()
+    }
+
+    /// returns all available [`Shard`]s in the form of [`Vec`]. Use [`Transcriptor`] to read from the [`Shard`]
+    pub fn heap() -> Vec<Shard> {
+        Self::snapshot()
+            .iter()
+            .flat_map(|(_, shards)| shards.iter().copied())
+            .collect()
+    }
+
+    /// returns every available [`Shard`] whose formatted text contains `query`, ignoring case
+    pub fn search(query: &str) -> Result<Vec<Shard>> {
+        Self::bundled().search(query)
+    }
+
+    /// same as [`Registry::search`], but reads and scans the notes in parallel. Matches keep the [`Registry::heap`] order
+    #[cfg(feature = "rayon")]
+    pub fn search_parallel(query: &str) -> Result<Vec<Shard>> {
+        use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+        let query = query.to_lowercase();
+        let found = Self::try_snapshot()?
+            .par_iter()
+            .map(|(_, shards)| {
+                let mut found = Vec::new();
+                for &shard in shards {
+                    if Transcriptor::read(&shard)?.to_lowercase().contains(&query) {
+                        found.push(shard);
+                    }
+                }
+                Ok(found)
+            })
+            .collect::<Result<Vec<Vec<Shard>>>>()?;
+        Ok(found.into_iter().flatten().collect())
+    }
+
+    /// returns every [`Shard`] found by [`Registry::search`], paired with a snippet of its formatted lines containing `query`,
+    /// where every occurrence is wrapped into `open` and `close` markers, like `**` and `**`. Matching ignores case,
+    /// but the snippet keeps the original one. An empty `query` finds nothing
+    pub fn search_marked(query: &str, open: &str, close: &str) -> Result<Vec<(Shard, String)>> {
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+        let query = query.to_lowercase();
+        let mut found = Vec::new();
+        for shard in Self::search(&query)? {
+            let snippet = Transcriptor::read(&shard)?
+                .lines()
+                .filter_map(|line| Self::marked(line, &query, open, close))
+                .collect::<Vec<String>>()
+                .join("\n");
+            found.push((shard, snippet));
+        }
+        Ok(found)
+    }
+
+    // wraps every occurrence of the lowercase `query` in the `line` into markers, or returns `None` if there is none
+    fn marked(line: &str, query: &str, open: &str, close: &str) -> Option<String> {
+        let mut marked = String::new();
+        let mut rest = line;
+        let mut found = false;
+        while let Some(c) = rest.chars().next() {
+            let length = match Self::match_length(rest, query) {
+                Some(length) => {
+                    found = true;
+                    marked.push_str(open);
+                    marked.push_str(&rest[..length]);
+                    marked.push_str(close);
+                    length
+                }
+                None => {
+                    marked.push(c);
+                    c.len_utf8()
+                }
+            };
+            rest = &rest[length..];
+        }
+        found.then_some(marked)
+    }
+
+    // byte length of the `text` prefix which equals the lowercase `query` ignoring case, if there is one
+    fn match_length(text: &str, query: &str) -> Option<usize> {
+        let mut lowered = String::new();
+        for (index, c) in text.char_indices() {
+            lowered.extend(c.to_lowercase());
+            if lowered == query {
+                return Some(index + c.len_utf8());
+            }
+            if !query.starts_with(lowered.as_str()) {
+                return None;
+            }
+        }
+        None
+    }
+
+    /// returns an iterator over batches of up to `size` [`Tablet`]s of the [`Registry::catalog`].
+    /// Every batch is built only when requested. Panics if `size` is 0
+    pub fn catalog_chunks(size: usize) -> impl Iterator<Item = Vec<Tablet>> {
+        let snapshot = Self::snapshot();
+        (0..snapshot.len()).step_by(size).map(move |start| {
+            snapshot[start..(start + size).min(snapshot.len())]
+                .iter()
+                .map(|&(tablet, _)| tablet)
+                .collect()
+        })
+    }
+
+    /// returns the [`Shard`] found at `index` in the [`Registry::heap`] order, or `None` if out of range.
+    /// Walks the cached snapshot without copying the whole heap
+    pub fn shard_by_index(index: usize) -> Option<Shard> {
+        Self::snapshot()
+            .iter()
+            .flat_map(|(_, shards)| shards.iter().copied())
+            .nth(index)
+    }
+
+    /// returns the `page` of [`Shard`]s in the [`Registry::heap`] order, `per_page` [`Shard`]s each, where page 0 is the first one.
+    /// An out of range page is empty. Walks the cached snapshot without copying the whole heap
+    pub fn heap_paginated(page: usize, per_page: usize) -> Vec<Shard> {
+        Self::snapshot()
+            .iter()
+            .flat_map(|(_, shards)| shards.iter().copied())
+            .skip(page.saturating_mul(per_page))
+            .take(per_page)
+            .collect()
+    }
+
+    /// returns every [`Shard`] whose formatted text matches the regular expression `pattern`
+    #[cfg(feature = "regex")]
+    pub fn search_regex(pattern: &str) -> std::result::Result<Vec<Shard>, SearchError> {
+        let regex = regex::Regex::new(pattern)?;
+        let mut found = Vec::new();
+        for shard in Self::heap() {
+            if regex.is_match(&Transcriptor::read(&shard)?) {
+                found.push(shard);
+            }
+        }
+        Ok(found)
+    }
+
+    /// returns every [`Tablet`] with an intra-doc link exactly matching `target`, case sensitive like Rust type names
+    pub fn referencing(target: &str) -> Result<Vec<Tablet>> {
+        let mut found = Vec::new();
+        for tablet in Self::catalog() {
+            if tablet.contains_link_to(target)? {
+                found.push(tablet);
+            }
+        }
+        Ok(found)
+    }
+
+    /// returns deduplicated edges `(tablet_name, linked_type)` built from intra-doc links of every available [`Tablet`],
+    /// in the [`Registry::catalog`] order and the link appearance order
+    pub fn topic_graph() -> Result<Vec<(String, String)>> {
+        let mut seen = HashSet::new();
+        let mut edges = Vec::new();
+        for tablet in Self::catalog() {
+            for link in tablet.links()? {
+                let edge = (tablet.name().to_string(), link);
+                if seen.insert(edge.clone()) {
+                    edges.push(edge);
+                }
+            }
+        }
+        Ok(edges)
+    }
+
+    /// returns paths of every note in the [`Registry`] whose file does not exist. Only checks existence, never opens the files
+    pub fn missing_files() -> Vec<&'static str> {
+        registry::TABLETS
+            .iter()
+            .copied()
+            .filter(|path| !Path::new(path).exists())
+            .collect()
+    }
+
+    /// checks every note of the [`Registry`] and reports unreadable files as errors and empty notes as warnings
+    #[must_use = "the report has to be checked with `is_ok()`"]
+    pub fn validate() -> ValidationReport {
+        let mut issues = Vec::new();
+        for &path in registry::TABLETS.iter() {
+            match Tablet::from_path(path) {
+                Ok(tablet) => match tablet.is_empty() {
+                    Ok(false) => {}
+                    Ok(true) => issues.push(ValidationIssue::Empty(path)),
+                    Err(err) => issues.push(ValidationIssue::Unreadable(path, err.to_string())),
+                },
+                Err(TabletError::Empty(_)) => issues.push(ValidationIssue::Empty(path)),
+                Err(err) => issues.push(ValidationIssue::Unreadable(path, err.to_string())),
+            }
+        }
+        ValidationReport { issues }
+    }
+
+    /// returns all available [`Shard`]s grouped by the [`Tablet::name`] they belong to, the same as in [`Registry::heap`]
+    pub fn heap_grouped_by_name() -> Result<BTreeMap<String, Vec<Shard>>> {
+        let mut grouped: BTreeMap<String, Vec<Shard>> = BTreeMap::new();
+        for (tablet, shards) in Self::try_snapshot()?.iter() {
+            grouped
+                .entry(tablet.name().to_string())
+                .or_default()
+                .extend(shards.iter().copied());
+        }
+        Ok(grouped)
+    }
+
+    /// returns all available [`Tablet`]s paired with their [`Shard`] counts, from the richest to the poorest.
+    /// Tablets with equal counts are ordered by name
+    pub fn catalog_by_shard_count() -> Result<Vec<(Tablet, usize)>> {
+        let mut counted: Vec<(Tablet, usize)> = Self::try_snapshot()?
+            .iter()
+            .map(|(tablet, shards)| (*tablet, shards.len()))
+            .collect();
+        counted.sort_by(|(a, a_count), (b, b_count)| {
+            b_count.cmp(a_count).then_with(|| a.name().cmp(b.name()))
+        });
+        Ok(counted)
+    }
+
+    // builds the whole `Tablet` and all its `Shard`s, reading the note file only once
+    fn split(path: &'static str) -> std::result::Result<(Tablet, Vec<Shard>), TabletError> {
+        Self::split_lines(path, &Self::source(path)?)
+    }
+
+    // reads every source line of the note file
+    fn source(path: &'static str) -> Result<Vec<String>> {
+        FsProvider.read_lines(path)
+    }
+
+    // builds the whole `Tablet` and all its `Shard`s from already read source `lines`
+    fn split_lines(
+        path: &'static str,
+        lines: &[String],
+    ) -> std::result::Result<(Tablet, Vec<Shard>), TabletError> {
+        if lines.is_empty() {
+            return Err(TabletError::Empty(path.to_string()));
+        }
+        let tablet = Tablet(path, (0, lines.len() - 1));
+        let shards = parse::segments(lines, 0)
+            .into_iter()
+            .map(|segment| Tablet(path, segment))
+            .collect();
+        Ok((tablet, shards))
+    }
+
+    /// returns all available [`Tablet`]s paired with their [`Tablet::display_name`], reading every note file only once
+    pub fn catalog_with_titles() -> Result<Vec<(Tablet, String)>> {
+        registry::TABLETS
+            .iter()
+            .map(|&path| {
+                let lines = Self::source(path)?;
+                let (tablet, _) = Self::split_lines(path, &lines)?;
+                let title = Transcriptor::title(&parse::format_all(&lines));
+                Ok((tablet, title.unwrap_or_else(|| tablet.fallback_name())))
+            })
+            .collect()
+    }
+
+    /// returns [`RegistryStats`] aggregated over all available [`Tablet`]s, reading every note file only once
+    pub fn stats() -> Result<RegistryStats> {
+        let mut stats = RegistryStats::default();
+        for &path in registry::TABLETS.iter() {
+            let lines = Self::source(path)?;
+            let (_, shards) = Self::split_lines(path, &lines)?;
+            let formatted = parse::format_all(&lines);
+            let mut code = formatted.iter().cloned().map(Ok);
+            while Transcriptor::next_code_block(&mut code)?.is_some() {
+                stats.code_blocks += 1;
+            }
+            stats.tablets += 1;
+            stats.shards += shards.len();
+            stats.lines += lines.len();
+            stats.words += Transcriptor::prose_words(&formatted).count();
+        }
+        Ok(stats)
+    }
+
+    /// returns all available [`Tablet`]s paired with their [`Tablet::reading_time`] at `wpm` words per minute,
+    /// reading every note file only once
+    pub fn catalog_with_reading_time(wpm: usize) -> Result<Vec<(Tablet, Duration)>> {
+        registry::TABLETS
+            .iter()
+            .map(|&path| {
+                let lines = Self::source(path)?;
+                let (tablet, _) = Self::split_lines(path, &lines)?;
+                let words = Transcriptor::prose_words(&parse::format_all(&lines)).count();
+                Ok((tablet, Transcriptor::reading_time(words, wpm)))
+            })
+            .collect()
+    }
+
+    /// returns every available [`Tablet`] with a `rust` code block mentioning `api`, case-sensitively, paired with the body
+    /// of its first such block, in the [`Registry::catalog`] order. Tablets without a match are skipped
+    pub fn examples_using(api: &str) -> Result<Vec<(Tablet, String)>> {
+        let mut examples = Vec::new();
+        for tablet in Self::catalog() {
+            let mut lines = Transcriptor::formatted_iter(&tablet)?;
+            while let Some(block) = Transcriptor::next_code_block(&mut lines)? {
+                if block.contains(api) {
+                    examples.push((tablet, block));
+                    break;
+                }
+            }
+        }
+        Ok(examples)
+    }
+
+    /// returns the zero-based position of the `shard` in the [`Registry::heap`] order, or `None` if there is no such [`Shard`].
+    /// Shards are compared by path and line range
+    pub fn shard_index(shard: &Shard) -> Result<Option<usize>> {
+        Ok(Self::try_snapshot()?
+            .iter()
+            .flat_map(|(_, shards)| shards)
+            .position(|other| other == shard))
+    }
+
+    /// returns the previous and the next [`Shard`] of `shard` in the [`Registry::heap`] order, crossing [`Tablet`] boundaries.
+    /// The first [`Shard`] has no previous one and the last has no next one. Both are `None` if there is no such [`Shard`]
+    pub fn shard_neighbors(shard: &Shard) -> Result<(Option<Shard>, Option<Shard>)> {
+        let heap: Vec<Shard> = Self::try_snapshot()?
+            .iter()
+            .flat_map(|(_, shards)| shards.iter().copied())
+            .collect();
+        let Some(index) = heap.iter().position(|other| other == shard) else {
+            return Ok((None, None));
+        };
+        let previous = index.checked_sub(1).map(|previous| heap[previous]);
+        Ok((previous, heap.get(index + 1).copied()))
+    }
+
+    /// writes every [`Tablet`] read by [`Transcriptor`] into the `dir` as `{name}.md` file, creating the `dir` if needed
+    pub fn export_all(dir: &Path) -> Result<()> {
+        Self::export_all_with_progress(dir, |_, _| {})
+    }
+
+    /// writes the [`Shard::flashcard`] of every available [`Shard`] as a `front\tback` row, one row per [`Shard`], ready for the `Anki` import.
+    /// Fields are encoded as HTML: `&`, `<`, `>` and `"` are escaped, line breaks become `<br>`, and tabs become `&#9;`
+    pub fn export_flashcards<W: Write>(w: &mut W) -> Result<()> {
+        for shard in Self::heap() {
+            let (front, back) = shard.flashcard()?;
+            writeln!(
+                w,
+                "{}\t{}",
+                Self::flashcard_field(&front),
+                Self::flashcard_field(&back)
+            )?;
+        }
+        Ok(())
+    }
+
+    // encodes the flashcard field as a single line of HTML
+    fn flashcard_field(field: &str) -> String {
+        Transcriptor::html_escape(field)
+            .replace("\r\n", "<br>")
+            .replace('\n', "<br>")
+            .replace('\t', "&#9;")
+    }
+
+    /// same as [`Registry::export_all`], but calls `progress` with `(done, total)` after each written file
+    pub fn export_all_with_progress<F: FnMut(usize, usize)>(
+        dir: &Path,
+        mut progress: F,
+    ) -> Result<()> {
+        fs::create_dir_all(dir)?;
+        let catalog = Self::catalog();
+        let total = catalog.len();
+        for (done, tablet) in catalog.iter().enumerate() {
+            let file = dir.join(format!("{}.md", tablet.name()));
+            fs::write(file, Transcriptor::read(tablet)?)?;
+            progress(done + 1, total);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    // Writes `contents` into a temporary note file and returns it as a whole Tablet
+    fn fixture(name: &str, contents: &str) -> Tablet {
+        let path = std::env::temp_dir().join(format!("rust_daily_{name}.rs"));
+        std::fs::write(&path, contents).expect(TABLET_UNREADABLE_MSG);
+        let path: &'static str = String::leak(path.to_string_lossy().into_owned());
+        Tablet(path, (0, contents.lines().count() - 1))
+    }
+
+    // Prints every single Tablet
+    #[test]
+    fn print_catalog() {
+        let cat = Registry::catalog();
+        print!("{}", "\n".repeat(7));
+        println!("{}", "==============================".repeat(4));
+        println!("\nVec Catalog: {:#?} Len: {}\n", cat, cat.len());
+        cat.iter().for_each(|tablet| {
+            println!(
+                "\n################# TABLET {name} #################\n[[{contents}]]",
+                name = tablet.name().to_ascii_uppercase(),
+                contents = Transcriptor::read(tablet).expect(TABLET_UNREADABLE_MSG)
+            )
+        });
+        println!("\n{}", "==============================".repeat(4));
+        print!("{}", "\n".repeat(7));
+    }
+
+    // Prints every single Shard
+    #[test]
+    fn print_heap() {
+        let heap = Registry::heap();
+        print!("{}", "\n".repeat(7));
+        println!("{}", "==============================".repeat(4));
+        println!("\nVec Heap: {:#?} Len: {}\n", heap, heap.len());
+        heap.iter().for_each(|shard| {
+            println!(
+                "\n################# shard {name}-{line} #################\n[[{contents}]]",
+                name = shard.name(),
+                line = shard.start(),
+                contents = Transcriptor::read(shard).expect(TABLET_UNREADABLE_MSG)
+            )
+        });
+        println!("\n{}", "==============================".repeat(4));
+        print!("{}", "\n".repeat(7));
+    }
+
+    #[test]
+    fn shard_by_index_follows_heap() {
+        let heap = Registry::heap();
+        heap.iter()
+            .enumerate()
+            .for_each(|(index, shard)| assert_eq!(Registry::shard_by_index(index), Some(*shard)));
+        assert_eq!(Registry::shard_by_index(heap.len()), None);
+        heap.iter().enumerate().for_each(|(index, shard)| {
+            assert_eq!(Registry::shard_index(shard).unwrap(), Some(index))
+        });
+    }
+
+    #[test]
+    fn catalog_chunks_cover_catalog() {
+        let chunks: Vec<Vec<Tablet>> = Registry::catalog_chunks(2).collect();
+        assert!(
+            chunks
+                .iter()
+                .all(|chunk| !chunk.is_empty() && chunk.len() <= 2)
+        );
+        assert_eq!(chunks.concat(), Registry::catalog());
+    }
+
+    #[test]
+    fn frontmatter_contains_metadata() {
+        let tablet = fixture(
+            "frontmatter",
+            "//! # Title \"Quoted\"\n//! @level: beginner\n//! @tags: memory, heap\n//!\n//! Body",
+        );
+        let rendered = Transcriptor::read_with_frontmatter(&tablet).unwrap();
+        assert!(rendered.starts_with(
+            "---\ntitle: \"Title \\\"Quoted\\\"\"\nlevel: \"beginner\"\ntags: [\"memory\", \"heap\"]\n---\n"
+        ));
+
+        let tablet = fixture("frontmatter_plain", "//! Body");
+        let rendered = Transcriptor::read_with_frontmatter(&tablet).unwrap();
+        assert_eq!(
+            rendered,
+            "---\ntitle: \"Rust Daily Frontmatter Plain\"\n---\nBody"
+        );
+    }
+
+    #[test]
+    fn metadata_is_stripped_from_body() {
+        let tablet = fixture(
+            "metadata",
+            "//! # Title\n//! @tags: memory\n//! @level: beginner\n//! Body",
+        );
+        assert_eq!(Transcriptor::read(&tablet).unwrap(), "# Title\nBody");
+        assert_eq!(
+            Transcriptor::read_raw(&tablet).unwrap(),
+            "# Title\n@tags: memory\n@level: beginner\nBody"
+        );
+        assert_eq!(tablet.tags().unwrap(), vec!["memory"]);
+        assert_eq!(tablet.level().unwrap().as_deref(), Some("beginner"));
+    }
+
+    #[test]
+    fn tablet_as_path() {
+        let tablet = Registry::catalog().into_iter().next().unwrap();
+        let metadata = std::fs::metadata(tablet).expect(TABLET_UNREADABLE_MSG);
+        assert!(metadata.is_file());
+        let borrowed: &Tablet = &tablet;
+        assert!(File::open(borrowed).is_ok());
+    }
+
+    #[test]
+    fn tablet_from_name() {
+        let tablet = Registry::catalog().into_iter().next().unwrap();
+        assert_eq!(tablet.name().parse::<Tablet>().unwrap(), tablet);
+        assert_eq!(Registry::find_by_name(tablet.name()), Some(tablet));
+        assert!(matches!(
+            "missing_note".parse::<Tablet>(),
+            Err(TabletError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn tablet_from_path() {
+        let tablet = Registry::catalog().into_iter().next().unwrap();
+        assert_eq!(Tablet::try_from(tablet.path_str()).unwrap(), tablet);
+        assert!(matches!(
+            Tablet::try_from("missing_note.rs"),
+            Err(TabletError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn stable_key_is_section_index() {
+        let tablet = Registry::catalog().into_iter().next().unwrap();
+        tablet.shards().enumerate().for_each(|(index, shard)| {
+            assert_eq!(
+                shard.stable_key().unwrap(),
+                (tablet.path_str().to_string(), index)
+            )
+        });
+    }
+
+    #[test]
+    fn read_range_matches_read() {
+        let shard = Registry::heap().into_iter().next().unwrap();
+        assert_eq!(
+            Transcriptor::read_range(shard.path(), shard.start(), shard.end()).unwrap(),
+            Transcriptor::read(&shard).unwrap()
+        );
+        let error = Transcriptor::read_range(shard.path(), 0, usize::MAX).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn export_all_reports_progress() {
+        let dir = std::env::temp_dir().join("rust_daily_export");
+        let mut reports = Vec::new();
+        Registry::export_all_with_progress(&dir, |done, total| reports.push((done, total)))
+            .unwrap();
+        let total = Registry::catalog().len();
+        assert_eq!(
+            reports,
+            (1..=total).map(|done| (done, total)).collect::<Vec<_>>()
+        );
+        assert!(
+            Registry::catalog()
+                .iter()
+                .all(|tablet| dir.join(format!("{}.md", tablet.name())).is_file())
+        );
+    }
+
+    #[test]
+    fn outline_has_heading_levels() {
+        let tablet = fixture(
+            "outline",
+            "//! # Title\n//! Body\n//! ## Section\n//! ### Subsection\n//! #NotHeading",
+        );
+        assert_eq!(
+            tablet.outline().unwrap(),
+            vec![
+                (1, "Title".to_string()),
+                (2, "Section".to_string()),
+                (3, "Subsection".to_string())
+            ]
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn search_regex_matches_shards() {
+        let found = Registry::search_regex(r"fn\s+\w+").unwrap();
+        assert!(!found.is_empty());
+        assert!(matches!(
+            Registry::search_regex("("),
+            Err(SearchError::Regex(_))
+        ));
+    }
+
+    #[test]
+    fn read_truncated_respects_char_boundary() {
+        let tablet = fixture("truncated", "//! Crab: 🦀🦀");
+        assert_eq!(
+            Transcriptor::read_truncated(&tablet, 7).unwrap(),
+            "Crab: 🦀…"
+        );
+        assert_eq!(
+            Transcriptor::read_truncated(&tablet, 8).unwrap(),
+            "Crab: 🦀🦀"
+        );
+        assert_eq!(Transcriptor::read_truncated(&tablet, 0).unwrap(), "…");
+    }
+
+    #[test]
+    fn shards_nth_skips_ahead() {
+        let tablet = Registry::catalog()
+            .into_iter()
+            .max_by_key(|tablet| tablet.shards().count())
+            .unwrap();
+        let shards: Vec<Shard> = tablet.shards().collect();
+        assert_eq!(tablet.shards().nth(2), shards.get(2).copied());
+        assert_eq!(tablet.shards().nth(usize::MAX), None);
+    }
+
+    #[test]
+    fn bundled_notes_are_valid() {
+        let report = Registry::validate();
+        assert!(report.is_ok(), "{report}");
+        assert!(report.to_string().ends_with("0 error(s), 0 warning(s)"));
+    }
+
+    #[test]
+    fn read_gfm_output() {
+        let tablet = fixture(
+            "gfm",
+            "//! # Title\n//!\n//!\n//! Text\n//! ```\n//! let a = 1;\n//!\n//!\n//! ```\n//! -----\n//! ```no_run\n//! ```",
+        );
+        assert_eq!(
+            Transcriptor::read_gfm(&tablet).unwrap(),
+            "# Title\n\nText\n```rust\nlet a = 1;\n\n\n```\n---\n```rust\n```"
+        );
+    }
+
+    #[test]
+    fn read_keeps_paragraph_breaks() {
+        let tablet = fixture(
+            "paragraphs",
+            "//! First paragraph\n//!\n//! Second paragraph\n//!   \n//! Third paragraph",
+        );
+        assert_eq!(
+            Transcriptor::read(&tablet).unwrap(),
+            "First paragraph\n\nSecond paragraph\n\nThird paragraph"
+        );
+    }
+
+    #[test]
+    fn empty_sections_are_not_counted() {
+        let tablet = fixture(
+            "empty_sections",
+            "//! -----\n//! # First\n//! -----\n//!\n//! -----\n//! -----\n//! # Second\n//! -----",
+        );
+        assert_eq!(tablet.shards().count(), 3);
+        assert_eq!(tablet.section_count_excluding_empty().unwrap(), 2);
+        let names: Vec<String> = tablet
+            .nonempty_shards()
+            .unwrap()
+            .iter()
+            .map(|shard| Transcriptor::read(shard).unwrap())
+            .collect();
+        assert_eq!(names, vec!["# First", "# Second"]);
+    }
+
+    #[test]
+    fn heap_grouped_by_name_matches_heap() {
+        let grouped = Registry::heap_grouped_by_name().unwrap();
+        assert!(grouped.keys().is_sorted());
+        Registry::catalog().iter().for_each(|tablet| {
+            assert_eq!(grouped[tablet.name()], tablet.shards().collect::<Vec<_>>())
+        });
+    }
+
+    #[test]
+    fn code_blocks_are_extracted() {
+        let tablet = fixture(
+            "code_blocks",
+            "//! Text\n//! ```text\n//! not rust\n//! ```\n//! ```\n//! let a = 1;\n//! let b = a;\n//! ```\n//! ```should_panic\n//! panic!();\n//! ```\n//! ```\n//! unclosed",
+        );
+        assert_eq!(
+            tablet.code_blocks().unwrap(),
+            vec!["let a = 1;\nlet b = a;", "panic!();"]
+        );
+        assert_eq!(
+            tablet.first_code_block().unwrap().as_deref(),
+            Some("let a = 1;\nlet b = a;")
+        );
+        assert_eq!(
+            fixture("no_code", "//! Text").first_code_block().unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn read_without_code_keeps_prose() {
+        let tablet = fixture(
+            "without_code",
+            "//! Before\n//! ```\n//! let a = 1;\n//! ```\n//! -----\n//! After\n//! ```text\n//! kept\n//! ```\n//! ```rust\n//! unclosed",
+        );
+        assert_eq!(
+            Transcriptor::read_without_code(&tablet).unwrap(),
+            "Before\n-----\nAfter\n```text\nkept\n```"
+        );
+    }
+
+    #[test]
+    fn no_missing_files() {
+        assert!(Registry::missing_files().is_empty());
+    }
+
+    #[test]
+    fn byte_segments_point_to_shards() {
+        let tablet = fixture("byte_segments", "//! 🦀 First\r\n//! -----\n//! Second\n");
+        let contents = std::fs::read(tablet.path()).unwrap();
+        let sections: Vec<&[u8]> = tablet
+            .byte_segments()
+            .unwrap()
+            .into_iter()
+            .map(|(start, end)| &contents[start as usize..end as usize])
+            .collect();
+        assert_eq!(
+            sections,
+            vec!["//! 🦀 First\r\n".as_bytes(), b"//! Second\n"]
+        );
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn read_mmap_matches_read() {
+        Registry::heap().iter().for_each(|shard| {
+            assert_eq!(
+                Transcriptor::read_mmap(shard).unwrap(),
+                Transcriptor::read(shard).unwrap()
+            )
+        });
+    }
+
+    #[test]
+    fn invalid_utf8_is_detected() {
+        let tablet = fixture("invalid_utf8", "//! Valid\n//! Invalid");
+        let mut contents = std::fs::read(tablet.path()).unwrap();
+        contents.extend_from_slice(&[0xF0, 0x9F]);
+        std::fs::write(tablet.path(), contents).unwrap();
+        assert!(!tablet.is_valid_utf8().unwrap());
+        assert_eq!(Transcriptor::read_lossy(&tablet), "Valid\nInvalid\u{FFFD}");
+        assert!(Tablet(tablet.path_str(), (0, 0)).is_valid_utf8().unwrap());
+    }
+
+    #[test]
+    fn shards_are_fused() {
+        let tablet = Registry::catalog().into_iter().next().unwrap();
+        let mut shards = tablet.shards();
+        shards.by_ref().for_each(drop);
+        assert_eq!(shards.next(), None);
+        assert_eq!(shards.next(), None);
+    }
+
+    #[test]
+    fn alias_boundary() {
+        let tablet = Registry::catalog()
+            .into_iter()
+            .find(|tablet| tablet.shards().count() > 1)
+            .unwrap();
+        let shard = tablet.shards().next().unwrap();
+        assert!(tablet.is_whole().unwrap());
+        assert!(!shard.is_whole().unwrap());
+        assert_eq!(tablet.as_shard().unwrap(), None);
+        assert_eq!(shard.as_shard().unwrap(), Some(shard));
+    }
+
+    #[test]
+    fn read_lines_range_is_clamped() {
+        let tablet = fixture(
+            "lines_range",
+            "//! Zero\n//! -----\n//! Two\n//! Three\n//! Four",
+        );
+        let shard = tablet.shards().nth(1).unwrap();
+        assert_eq!(
+            Transcriptor::read_lines_range(&shard, 1, 1).unwrap(),
+            "Three"
+        );
+        assert_eq!(
+            Transcriptor::read_lines_range(&shard, 1, 100).unwrap(),
+            "Three\nFour"
+        );
+        assert_eq!(Transcriptor::read_lines_range(&shard, 5, 10).unwrap(), "");
+    }
+
+    #[test]
+    fn links_are_found_in_prose() {
+        let tablet = fixture(
+            "links",
+            "//! [`String`] is based on [`Vec<u8>`], not [`str`]\n//! ```\n//! let s = \"[`Code`]\";\n//! ```\n//! [`String`] again",
+        );
+        assert_eq!(
+            tablet.links().unwrap(),
+            vec!["String", "Vec<u8>", "str", "String"]
+        );
+        assert!(tablet.contains_link_to("Vec<u8>").unwrap());
+        assert!(!tablet.contains_link_to("Code").unwrap());
+        assert!(!tablet.contains_link_to("string").unwrap());
+    }
+
+    #[test]
+    fn referencing_finds_backlinks() {
+        let found = Registry::referencing("Sized").unwrap();
+        assert!(found.iter().any(|tablet| tablet.name() == "sized_trait"));
+        assert!(Registry::referencing("sized").unwrap().is_empty());
+    }
+
+    #[test]
+    fn cached_tablet_reads_once() {
+        let tablet = fixture("cached", "//! Before");
+        let cached = CachedTablet::from(tablet);
+        assert_eq!(cached.read().unwrap(), "Before");
+        std::fs::write(tablet.path(), "//! After").unwrap();
+        assert_eq!(cached.read().unwrap(), "Before");
+        assert_eq!(Transcriptor::read(&cached.tablet()).unwrap(), "After");
+    }
+
+    #[test]
+    fn read_shifted_headings() {
+        let tablet = fixture(
+            "shifted",
+            "//! # Title\n//! ##### Deep\n//! ```\n//! # hidden line\n//! ```",
+        );
+        assert_eq!(
+            Transcriptor::read_shifted(&tablet, 2).unwrap(),
+            "### Title\n###### Deep\n```\n# hidden line\n```"
+        );
+    }
+
+    #[test]
+    fn custom_registry_over_paths() {
+        let first = fixture("custom_first", "//! # First\n//! -----\n//! Needle");
+        let second = fixture("custom_second", "//! # Second");
+        let custom = RegistryBuilder::new()
+            .add_path(first.path_str())
+            .add_path(second.path_str())
+            .build()
+            .unwrap();
+        assert_eq!(custom.catalog(), vec![first, second]);
+        assert_eq!(custom.heap().len(), 3);
+        assert_eq!(
+            custom.search("NEEDLE").unwrap(),
+            vec![Tablet(first.path_str(), (2, 2))]
+        );
+    }
+
+    #[test]
+    fn code_indent_is_preserved() {
+        let tablet = fixture(
+            "code_indent",
+            "//!   Prose\n//! ```\n//! if true {\n//!     if true {\n//!         println!();\n//!     }\n//! }\n//! ```",
+        );
+        assert_eq!(
+            Transcriptor::read(&tablet).unwrap(),
+            "Prose\n```\nif true {\n    if true {\n        println!();\n    }\n}\n```"
+        );
+        assert_eq!(
+            tablet.first_code_block().unwrap().as_deref(),
+            Some("if true {\n    if true {\n        println!();\n    }\n}")
+        );
+        let options = ReadOptions::new().preserve_code_indent(false);
+        assert_eq!(
+            Transcriptor::read_with(&tablet, &options).unwrap(),
+            "Prose\n```\nif true {\nif true {\nprintln!();\n}\n}\n```"
+        );
+    }
+
+    #[test]
+    fn stats_match_catalog() {
+        let stats = Registry::stats().unwrap();
+        let catalog = Registry::catalog();
+        assert_eq!(stats.tablets, catalog.len());
+        assert_eq!(stats.shards, Registry::heap().len());
+        assert_eq!(
+            stats.lines,
+            catalog.iter().map(Tablet::length).sum::<usize>()
+        );
+        let code_blocks: usize = catalog
+            .iter()
+            .map(|tablet| tablet.code_blocks().unwrap().len())
+            .sum();
+        assert_eq!(stats.code_blocks, code_blocks);
+        let words: usize = catalog
+            .iter()
+            .map(|tablet| tablet.word_count().unwrap())
+            .sum();
+        assert_eq!(stats.words, words);
+    }
+
+    #[test]
+    fn read_wrapped_between_words() {
+        let tablet = fixture(
+            "wrapped",
+            "//! # A heading longer than twenty chars\n//! Rust strings are 🦀 UTF-8 encoded sequences\n//! ```\n//! let code_is_never_wrapped = true;\n//! ```",
+        );
+        assert_eq!(
+            Transcriptor::read_wrapped(&tablet, 20).unwrap(),
+            "# A heading longer than twenty chars\nRust strings are 🦀\nUTF-8 encoded\nsequences\n```\nlet code_is_never_wrapped = true;\n```"
+        );
+    }
+
+    #[test]
+    fn catalog_titles_are_headings() {
+        Registry::catalog_with_titles()
+            .unwrap()
+            .into_iter()
+            .for_each(|(tablet, title)| {
+                assert!(!title.is_empty());
+                assert_eq!(title, tablet.display_name().unwrap());
+            });
+        let tablet = fixture("untitled", "//! No heading");
+        assert_eq!(tablet.display_name().unwrap(), "Rust Daily Untitled");
+    }
+
+    #[test]
+    fn words_skip_code() {
+        let tablet = fixture(
+            "words",
+            "//! # Strings\n//! - `str` is a slice\n//! ```\n//! let skipped = 1;\n//! ```\n//! Done!",
+        );
+        assert_eq!(
+            tablet.words().unwrap(),
+            vec!["Strings", "`str`", "is", "a", "slice", "Done!"]
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_catalog_keeps_order() {
+        use rayon::iter::ParallelIterator;
+        let names: Vec<String> = Registry::par_catalog()
+            .map(|tablet| tablet.name().to_string())
+            .collect();
+        let expected: Vec<String> = Registry::catalog()
+            .iter()
+            .map(|tablet| tablet.name().to_string())
+            .collect();
+        assert_eq!(names, expected);
+    }
+
+    #[test]
+    fn toc_links_headings() {
+        let tablet = fixture(
+            "toc",
+            "//! # Strings In Rust\n//! ## `str` & String!\n//! # Strings In Rust",
+        );
+        assert_eq!(
+            Transcriptor::toc(&tablet).unwrap(),
+            "- [Strings In Rust](#strings-in-rust)\n  - [`str` & String!](#str--string)\n- [Strings In Rust](#strings-in-rust-1)"
+        );
+        assert_eq!(
+            Transcriptor::toc(&fixture("no_toc", "//! Text")).unwrap(),
+            ""
+        );
+    }
+
+    #[test]
+    fn context_around_match() {
+        let tablet = fixture(
+            "context",
+            "//! One\n//! Two\n//! Three NEEDLE\n//! Four\n//! Five",
+        );
+        assert_eq!(
+            tablet.context("needle", 1).unwrap().as_deref(),
+            Some("Two\nThree NEEDLE\nFour")
+        );
+        assert_eq!(
+            tablet
+                .context("needle", 10)
+                .unwrap()
+                .unwrap()
+                .lines()
+                .count(),
+            5
+        );
+        assert_eq!(tablet.context("missing", 1).unwrap(), None);
+    }
+
+    #[test]
+    fn separators_match_whole_lines() {
+        let tablet = fixture(
+            "separators",
+            "//! First\n//! ----------\n//! Text with ----- inside\n//! -----\n//! Last",
+        );
+        assert_eq!(tablet.separator_lines().unwrap(), vec![1, 3]);
+        assert_eq!(tablet.shards().count(), 3);
+    }
+
+    #[test]
+    fn read_json_string_escapes() {
+        let tablet = fixture("json", "//! let s = \"quoted\\path\";\n//! tab\there\u{1}");
+        assert_eq!(
+            Transcriptor::read_json_string(&tablet).unwrap(),
+            r#"let s = \"quoted\\path\";\ntab\there\u0001"#
+        );
+    }
+
+    #[test]
+    fn matches_multiple_keywords() {
+        let tablet = fixture("keywords", "//! Ownership and Borrowing");
+        assert!(tablet.matches_all(&["ownership", "BORROWING"]).unwrap());
+        assert!(!tablet.matches_all(&["ownership", "lifetimes"]).unwrap());
+        assert!(tablet.matches_any(&["lifetimes", "borrow"]).unwrap());
+        assert!(!tablet.matches_any(&["lifetimes"]).unwrap());
+    }
+
+    #[test]
+    fn display_title_falls_back_to_section() {
+        let tablet = fixture("display_title", "//! # Heading\n//! -----\n//! No heading");
+        let titles: Vec<String> = tablet
+            .shards()
+            .map(|shard| shard.display_title().unwrap())
+            .collect();
+        assert_eq!(
+            titles,
+            vec!["Heading", "Rust Daily Display Title — section 2"]
+        );
+    }
+
+    #[test]
+    fn parse_segments_without_io() {
+        let lines = ["//! a", "//! -----", "//! b", "//! c"];
+        assert_eq!(parse::segments(lines, 10), vec![(10, 10), (12, 13)]);
+        let shards = Shards::from_lines(Tablet::new("note.rs", 10, 13), lines);
+        assert_eq!(
+            shards.collect::<Vec<_>>(),
+            vec![
+                Tablet::new("note.rs", 10, 10),
+                Tablet::new("note.rs", 12, 13)
+            ]
+        );
+        assert_eq!(parse::heading("## Title"), Some((2, "Title")));
+        assert_eq!(Tablet::new("note.rs", 3, 7).length(), 5);
+    }
+
+    #[test]
+    fn in_memory_provider_without_files() {
+        let provider =
+            InMemoryProvider::new().add("memory/note.rs", "//! # Title\n//! -----\n//! Body");
+        let registry = RegistryBuilder::new()
+            .add_path("memory/note.rs")
+            .build_with(provider.clone())
+            .unwrap();
+        assert_eq!(
+            registry.catalog(),
+            vec![Tablet::new("memory/note.rs", 0, 2)]
+        );
+        assert_eq!(registry.heap().len(), 2);
+        let found = registry.search("body").unwrap();
+        assert_eq!(found, vec![Tablet::new("memory/note.rs", 2, 2)]);
+        assert_eq!(
+            Transcriptor::read_from(&provider, &found[0], &ReadOptions::default()).unwrap(),
+            Transcriptor::read(&fixture("memory", "//! Body")).unwrap()
+        );
+        let tablet = registry.catalog()[0];
+        assert_eq!(
+            registry.shards(&tablet).unwrap().collect::<Vec<_>>(),
+            registry.heap()
+        );
+        assert_eq!(registry.title(&tablet).unwrap().as_deref(), Some("Title"));
+        assert_eq!(registry.read(&found[0]).unwrap(), "Body");
+        assert_eq!(
+            provider.read_lines("missing.rs").unwrap_err().kind(),
+            ErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn read_html_output() {
+        let tablet = fixture(
+            "html",
+            "//! # A & B\n//! @tags: x\n//! First <line>\n//! second\n//! -----\n//! ```\n//! let a = 1 < 2;\n//! ```\n//! ```text\n//! plain\n//! ```",
+        );
+        assert_eq!(
+            Transcriptor::read_html(&tablet).unwrap(),
+            "<h1 id=\"a--b\">A &amp; B</h1>\n<p>First &lt;line&gt;\nsecond</p>\n<hr>\n\
+             <pre><code class=\"language-rust\">let a = 1 &lt; 2;</code></pre>\n\
+             <pre><code class=\"language-text\">plain</code></pre>"
+        );
+    }
+
+    #[cfg(feature = "syntect")]
+    #[test]
+    fn read_highlighted_html_styles_rust() {
+        let tablet = fixture("highlighted", "//! Text\n//! ```\n//! let a = 1;\n//! ```");
+        let html = Transcriptor::read_highlighted_html(&tablet).unwrap();
+        assert!(html.starts_with("<p>Text</p>\n<pre style="));
+        assert!(html.contains("<span style="));
+    }
+
+    #[test]
+    fn first_line_skips_blank_lines() {
+        let tablet = fixture("first_line", "//!\n//!   \n//! # Title\n//! Body");
+        assert_eq!(tablet.first_line().unwrap().as_deref(), Some("# Title"));
+        let blank = fixture("first_line_blank", "//!\n//!");
+        assert_eq!(blank.first_line().unwrap(), None);
+    }
+
+    #[test]
+    fn catalog_names_match_catalog() {
+        let names: Vec<&str> = Registry::catalog()
+            .iter()
+            .map(|tablet| tablet.name())
+            .collect();
+        assert_eq!(Registry::catalog_names(), names);
+    }
+
+    #[test]
+    fn example_count_matches_code_blocks() {
+        let tablet = fixture(
+            "examples",
+            "//! ```\n//! a\n//! ```\n//! ```text\n//! b\n//! ```\n//! -----\n//! ```rust\n//! c\n//! ```\n//! ```\n//! unclosed",
+        );
+        assert_eq!(
+            tablet.example_count().unwrap(),
+            tablet.code_blocks().unwrap().len()
+        );
+        let counts: Vec<usize> = tablet
+            .shards()
+            .map(|shard| shard.example_count().unwrap())
+            .collect();
+        assert_eq!(counts, vec![1, 1]);
+    }
+
+    #[test]
+    fn read_with_callouts_above_code() {
+        let tablet = fixture(
+            "callouts",
+            "//! Text\n//! ```\n//! let b = a;\n//! // Error: use of moved value\n//! // fine\n//! ```\n//! ```\n//! // problem here",
+        );
+        assert_eq!(
+            Transcriptor::read_with_callouts(&tablet).unwrap(),
+            "Text\n> ⚠️ Error: use of moved value\n\n```\nlet b = a;\n// Error: use of moved value\n// fine\n```\n\
+             > ⚠️ problem here\n\n```\n// problem here"
+        );
+        assert!(!Transcriptor::read(&tablet).unwrap().contains('⚠'));
+    }
+
+    #[test]
+    fn find_all_by_name_matches_find_by_name() {
+        let first = Registry::catalog()[0];
+        let found = Registry::find_all_by_name(&first.name().to_uppercase());
+        assert_eq!(found.first(), Registry::find_by_name(first.name()).as_ref());
+        assert!(found.iter().all(|tablet| tablet.name_matches(first.name())));
+        assert!(Registry::find_all_by_name("no_such_note").is_empty());
+    }
+
+    #[test]
+    fn chars_stream_unicode() {
+        let tablet = fixture("chars", "//! # 🦀 Crab\n//! ok");
+        let chars: Vec<char> = tablet.chars().unwrap().collect();
+        assert_eq!(chars[..3], ['#', ' ', '🦀']);
+        assert_eq!(
+            String::from_iter(chars),
+            Transcriptor::read(&tablet).unwrap()
+        );
+    }
+
+    #[test]
+    fn try_iter_matches_catalog() {
+        let tablets = Registry::try_iter()
+            .collect::<Result<Vec<Tablet>>>()
+            .unwrap();
+        assert_eq!(tablets, Registry::catalog());
+    }
+
+    #[test]
+    fn read_sections_match_shards() {
+        let tablet = fixture(
+            "sections",
+            "//! # One\n//! ```\n//!     indented\n//! ```\n//! -----\n//! @tags: a\n//! Two\n//! -----\n//! -----\n//! Three",
+        );
+        let per_shard: Vec<String> = tablet
+            .shards()
+            .map(|shard| Transcriptor::read(&shard).unwrap())
+            .collect();
+        assert_eq!(Transcriptor::read_sections(&tablet).unwrap(), per_shard);
+        assert_eq!(per_shard.len(), 3);
+    }
+
+    #[test]
+    fn section_anchors_match_html_ids() {
+        let tablet = fixture(
+            "anchors",
+            "//! # Intro\n//! ## Intro\n//! -----\n//! Plain\n//! -----\n//! # Intro",
+        );
+        let anchors = tablet.section_anchors().unwrap();
+        assert_eq!(
+            anchors,
+            vec![
+                ("Intro".to_string(), "intro".to_string()),
+                (
+                    "Rust Daily Anchors — section 2".to_string(),
+                    "section-2".to_string()
+                ),
+                ("Intro".to_string(), "intro-2".to_string()),
+            ]
+        );
+        let html = Transcriptor::read_html(&tablet).unwrap();
+        assert!(html.contains("<h1 id=\"intro\">") && html.contains("<h1 id=\"intro-2\">"));
+    }
+
+    #[test]
+    fn by_path_prefix_filters_paths() {
+        assert_eq!(
+            Registry::by_path_prefix("src/registry/"),
+            Registry::catalog()
+        );
+        assert_eq!(
+            Registry::by_path_prefix(env!("CARGO_MANIFEST_DIR")),
+            Registry::catalog()
+        );
+        let first = Registry::catalog()[0];
+        assert_eq!(Registry::by_path_prefix(first.path_str()), vec![first]);
+        assert!(Registry::by_path_prefix("src/registry/no_such_dir/").is_empty());
+    }
+
+    #[test]
+    fn trimmed_range_skips_blank_edges() {
+        let tablet = fixture(
+            "trimmed",
+            "//!\n//! First\n//!\n//! Last\n//!   \n//! -----\n//!\n//! Only\n//!",
+        );
+        let ranges: Vec<(usize, usize)> = tablet
+            .shards()
+            .map(|shard| shard.trimmed_range().unwrap())
+            .collect();
+        assert_eq!(ranges, vec![(1, 3), (7, 7)]);
+        let blank = fixture("trimmed_blank", "//!\n//!");
+        assert_eq!(blank.trimmed_range().unwrap(), (0, 1));
+    }
+
+    #[test]
+    fn separators_of_any_width_split() {
+        let tablet = fixture(
+            "widths",
+            "//! One\n//! ---\n//! Two\n//! -----\n//! Three\n//! ----------\n//! Four\n//! --\n//! - item",
+        );
+        assert_eq!(tablet.shards().count(), 4);
+        assert_eq!(tablet.separator_lines().unwrap(), vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn parent_tablet_of_shards() {
+        let tablet = fixture("parent", "//! One\n//! -----\n//! Two");
+        for shard in tablet.shards() {
+            assert_eq!(shard.parent_tablet().unwrap(), tablet);
+        }
+    }
+
+    #[test]
+    fn group_by_directory_keys_parents() {
+        let groups = Registry::group_by_directory();
+        let directory = concat!(env!("CARGO_MANIFEST_DIR"), "/src/registry");
+        assert_eq!(groups.keys().collect::<Vec<&String>>(), vec![directory]);
+        assert_eq!(groups[directory], Registry::catalog());
+    }
+
+    #[test]
+    fn read_strips_every_doc_prefix() {
+        let tablet = fixture(
+            "prefixes",
+            "/// # Title\n    /// Outer text\n/// ```\n///     let a = 1; // note\n/// ```\n// -----\n// Plain comment\n//! Inner",
+        );
+        let sections = Transcriptor::read_sections(&tablet).unwrap();
+        assert_eq!(
+            sections,
+            vec![
+                "# Title\nOuter text\n```\n    let a = 1; // note\n```",
+                "Plain comment\nInner",
+            ]
+        );
+    }
+
+    #[test]
+    fn shard_neighbors_cross_tablets() {
+        let heap = Registry::heap();
+        let last = heap.len() - 1;
+        assert_eq!(
+            Registry::shard_neighbors(&heap[0]).unwrap(),
+            (None, heap.get(1).copied())
+        );
+        assert_eq!(
+            Registry::shard_neighbors(&heap[last]).unwrap(),
+            (last.checked_sub(1).map(|i| heap[i]), None)
+        );
+        let boundary = Registry::catalog()[0].shards().count();
+        assert_eq!(
+            Registry::shard_neighbors(&heap[boundary]).unwrap(),
+            (Some(heap[boundary - 1]), heap.get(boundary + 1).copied())
+        );
+        let missing = fixture("neighbors", "//! Alone");
+        assert_eq!(Registry::shard_neighbors(&missing).unwrap(), (None, None));
+    }
+
+    #[test]
+    fn read_with_footnotes_collects_urls() {
+        let tablet = fixture(
+            "footnotes",
+            "//! See https://doc.rust-lang.org/book/. And http://a.b/c, again https://doc.rust-lang.org/book/\n//! [docs](https://docs.rs) <https://kept.org>\n//! ```\n//! // https://in.code\n//! ```",
+        );
+        assert_eq!(
+            Transcriptor::read_with_footnotes(&tablet).unwrap(),
+            "See [^1]. And [^2], again [^1]\n[docs](https://docs.rs) <https://kept.org>\n```\n// https://in.code\n```\n\n\
+             [^1]: https://doc.rust-lang.org/book/\n[^2]: http://a.b/c"
+        );
+    }
+
+    #[test]
+    fn shard_lengths_match_shards() {
+        let tablet = fixture(
+            "lengths",
+            "//! a\n//! b\n//! -----\n//! c\n//! -----\n//! d\n//! e\n//! f",
+        );
+        let lengths: Vec<usize> = tablet.shards().map(|shard| shard.length()).collect();
+        assert_eq!(tablet.shard_lengths().unwrap(), lengths);
+        assert_eq!(lengths, vec![2, 1, 3]);
+    }
+
+    #[test]
+    fn heap_paginated_slices_heap() {
+        let heap = Registry::heap();
+        assert_eq!(Registry::heap_paginated(0, 3), heap[..3.min(heap.len())]);
+        assert_eq!(
+            Registry::heap_paginated(1, 2),
+            heap[2.min(heap.len())..4.min(heap.len())]
+        );
+        assert!(Registry::heap_paginated(heap.len(), 1).is_empty());
+        assert!(Registry::heap_paginated(0, 0).is_empty());
+    }
+
+    #[test]
+    fn eq_content_normalized_ignores_spacing() {
+        let first = fixture(
+            "spacing_first",
+            "//! # Title\n//! Some   text\n//! ```\n//!     code\n//! ```",
+        );
+        let second = fixture(
+            "spacing_second",
+            "//!\n//! # Title\n//! Some text\n//! ```\n//! code\n//! ```",
+        );
+        let other = fixture("spacing_other", "//! # Title\n//! Other text");
+        assert!(!first.eq_content(&second).unwrap());
+        assert!(first.eq_content(&first).unwrap());
+        assert!(first.eq_content_normalized(&second).unwrap());
+        assert!(!first.eq_content_normalized(&other).unwrap());
+    }
+
+    #[test]
+    fn read_error_points_at_tablet() {
+        let missing = Tablet::new("/no/such/note.rs", 0, 0);
+        let err = Transcriptor::read(&missing).unwrap_err();
+        assert_eq!(
+            (err.path(), err.line(), err.kind()),
+            ("/no/such/note.rs", None, ErrorKind::NotFound)
+        );
+        assert!(
+            err.to_string()
+                .starts_with("failed to read `/no/such/note.rs`: ")
+        );
+        assert_eq!(io::Error::from(err).kind(), ErrorKind::NotFound);
+
+        let tablet = fixture("read_error", "//! fine\n//! fine");
+        let mut contents = std::fs::read(tablet.path()).unwrap();
+        contents.extend_from_slice(b"\n//! \xFF");
+        std::fs::write(tablet.path(), contents).unwrap();
+        let broken = Tablet::new(tablet.path_str(), 0, 2);
+        let err = Transcriptor::read(&broken).unwrap_err();
+        assert_eq!((err.line(), err.kind()), (Some(2), ErrorKind::InvalidData));
+    }
+
+    #[test]
+    fn reload_keeps_catalog_consistent() {
+        let before = Registry::catalog();
+        assert_eq!(Registry::reload().unwrap(), before);
+        assert_eq!(Registry::catalog(), before);
+    }
+
+    #[test]
+    fn read_with_normalized_headings() {
+        let tablet = fixture(
+            "headings_spacing",
+            "//! #NoSpace\n//! ##Sub\n//! # Fine\n//! #[attr]\n//! #######Seven\n//! ```\n//! #x\n//! ```",
+        );
+        let options = ReadOptions::new().normalize_headings(true);
+        assert_eq!(
+            Transcriptor::read_with(&tablet, &options).unwrap(),
+            "# NoSpace\n## Sub\n# Fine\n#[attr]\n#######Seven\n```\n#x\n```"
+        );
+        assert!(Transcriptor::read(&tablet).unwrap().starts_with("#NoSpace"));
+    }
+
+    #[test]
+    fn outline_skips_code_attributes() {
+        let tablet = fixture(
+            "attributes",
+            "//! ```\n//! # hidden\n//! #[derive(Debug)]\n//! ```\n//! #[not_heading]\n//! ## Real",
+        );
+        assert_eq!(tablet.outline().unwrap(), vec![(2, "Real".to_string())]);
+        assert_eq!(tablet.title().unwrap(), None);
+        assert_eq!(Transcriptor::toc(&tablet).unwrap(), "- [Real](#real)");
+    }
+
+    #[test]
+    fn reading_time_from_word_count() {
+        let tablet = fixture(
+            "reading",
+            "//! # One two\n//! three - four\n//! ```\n//! let code = 1;\n//! ```",
+        );
+        assert_eq!(tablet.word_count().unwrap(), 4);
+        assert_eq!(tablet.reading_time(2).unwrap(), Duration::from_secs(120));
+        let timed = Registry::catalog_with_reading_time(200).unwrap();
+        let expected: Vec<(Tablet, Duration)> = Registry::catalog()
+            .into_iter()
+            .map(|tablet| (tablet, tablet.reading_time(200).unwrap()))
+            .collect();
+        assert_eq!(timed, expected);
+    }
+
+    #[test]
+    fn flashcard_front_and_back() {
+        let tablet = fixture(
+            "flashcard",
+            "//! Intro\n//! ## Question\n//! Answer\n//! -----\n//! No heading",
+        );
+        let cards: Vec<(String, String)> = tablet
+            .shards()
+            .map(|shard| shard.flashcard().unwrap())
+            .collect();
+        assert_eq!(
+            cards,
+            vec![
+                ("Question".to_string(), "Intro\nAnswer".to_string()),
+                ("Rust Daily Flashcard".to_string(), "No heading".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn export_flashcards_rows() {
+        let mut out = Vec::new();
+        Registry::export_flashcards(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.lines().count(), Registry::heap().len());
+        assert!(out.lines().all(|row| row.matches('\t').count() == 1));
+        assert_eq!(
+            Registry::flashcard_field("a\tb\n<c> & d"),
+            "a&#9;b<br>&lt;c&gt; &amp; d"
+        );
+    }
+
+    #[test]
+    fn code_contains_only_rust_code() {
+        let tablet = fixture(
+            "code_contains",
+            "//! Use Box::new in prose\n//! ```text\n//! Rc::new\n//! ```\n//! ```\n//! let b = Box::new(1);\n//! ```",
+        );
+        assert!(tablet.code_contains("Box::new").unwrap());
+        assert!(!tablet.code_contains("box::new").unwrap());
+        assert!(!tablet.code_contains("Rc::new").unwrap());
+        assert!(!tablet.code_contains("prose").unwrap());
+    }
+
+    #[test]
+    fn examples_using_finds_first_block() {
+        let api = "String::from";
+        let examples = Registry::examples_using(api).unwrap();
+        let expected: Vec<Tablet> = Registry::catalog()
+            .into_iter()
+            .filter(|tablet| tablet.code_contains(api).unwrap())
+            .collect();
+        assert_eq!(
+            examples
+                .iter()
+                .map(|(tablet, _)| *tablet)
+                .collect::<Vec<Tablet>>(),
+            expected
+        );
+        for (tablet, block) in &examples {
+            let first = tablet
+                .code_blocks()
+                .unwrap()
+                .into_iter()
+                .find(|block| block.contains(api));
+            assert_eq!(Some(block), first.as_ref());
+        }
+        assert!(
+            Registry::examples_using("NoSuchApi::call")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn read_stripped_doctests_hides_lines() {
+        let tablet = fixture(
+            "doctests",
+            "//! # Title\n//! ```\n//! # use std::rc::Rc;\n//! #\n//!     # let hidden = 1;\n//! let shown = 2;\n//! #[derive(Debug)]\n//! ```\n//! ```text\n//! # kept\n//! ```",
+        );
+        assert_eq!(
+            Transcriptor::read_stripped_doctests(&tablet).unwrap(),
+            "# Title\n```\nlet shown = 2;\n#[derive(Debug)]\n```\n```text\n# kept\n```"
+        );
+        assert!(
+            Transcriptor::read(&tablet)
+                .unwrap()
+                .contains("# use std::rc::Rc;")
+        );
+    }
+
+    #[test]
+    fn distinct_links_counted_once() {
+        let tablet = fixture(
+            "distinct_links",
+            "//! [`String`] and [`Vec`], again [`String`]\n//! ```\n//! // [`Box`]\n//! ```",
+        );
+        assert_eq!(tablet.links().unwrap().len(), 3);
+        assert_eq!(tablet.distinct_links_count().unwrap(), 2);
+    }
+
+    #[test]
+    fn search_marked_wraps_every_occurrence() {
+        assert_eq!(
+            Registry::marked("Box and box, BOX", "box", "**", "**").as_deref(),
+            Some("**Box** and **box**, **BOX**")
+        );
+        assert_eq!(Registry::marked("nothing here", "box", "[", "]"), None);
+        let marked = Registry::search_marked("Ownership", "<", ">").unwrap();
+        assert_eq!(
+            marked
+                .iter()
+                .map(|(shard, _)| *shard)
+                .collect::<Vec<Shard>>(),
+            Registry::search("ownership").unwrap()
+        );
+        assert!(
+            marked
+                .iter()
+                .all(|(_, snippet)| snippet.to_lowercase().contains("<ownership>"))
+        );
+        assert!(Registry::search_marked("", "<", ">").unwrap().is_empty());
+    }
+
+    #[test]
+    fn length_cached_after_catalog() {
+        let catalog = Registry::catalog();
+        assert_eq!(catalog[0].length_cached(), Some(catalog[0].length()));
+        let shard = catalog[0].shards().last().unwrap();
+        assert_eq!(shard.length_cached(), None);
+        assert_eq!(fixture("uncached", "//! Not bundled").length_cached(), None);
+    }
+
+    #[test]
+    fn read_collapses_blank_lines() {
+        let tablet = fixture(
+            "blank_runs",
+            "//! First\n//!\n//!\n//!\n//!\n//!\n//! Second\n//! ```\n//!\n//!\n//! ```",
+        );
+        assert_eq!(
+            Transcriptor::read(&tablet).unwrap(),
+            "First\n\nSecond\n```\n\n\n```"
+        );
+        let options = ReadOptions::new().max_consecutive_blank_lines(2);
+        assert_eq!(
+            Transcriptor::read_with(&tablet, &options).unwrap(),
+            "First\n\n\nSecond\n```\n\n\n```"
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn search_parallel_matches_search() {
+        for query in ["ownership", "String", "no such text"] {
+            assert_eq!(
+                Registry::search_parallel(query).unwrap(),
+                Registry::search(query).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn catalog_count_is_static() {
+        assert_eq!(Registry::catalog_count_cached(), Registry::catalog().len());
+    }
+
+    #[test]
+    fn separator_lines_skip_code_comments() {
+        let tablet = fixture(
+            "code_comment_separator",
+            "//! Text\n//! ```\n//! // -----\n//! ```\n//! After",
+        );
+        assert_eq!(tablet.shards().count(), 1);
+        assert!(tablet.separator_lines().unwrap().is_empty());
+        assert!(!parse::is_formatted_separator("// -----"));
+        assert!(parse::is_separator("//! -----"));
+    }
+
+    #[test]
+    fn code_block_dashes_do_not_split() {
+        let tablet = fixture(
+            "yaml_block",
+            "//! Config\n//! ```text\n//! ---\n//! key: value\n//! ---\n//! ```\n//! -----\n//! Next",
+        );
+        assert_eq!(
+            tablet.shards().map(|shard| shard.1).collect::<Vec<_>>(),
+            vec![(0, 5), (7, 7)]
+        );
+        assert_eq!(tablet.separator_lines().unwrap(), vec![6]);
+    }
+
+    #[test]
+    fn callouts_of_trailing_comments() {
+        let note = |file: &str| {
+            Registry::catalog()
+                .into_iter()
+                .find(|tablet| tablet.path_str().ends_with(file))
+                .unwrap()
+        };
+        let ownership = Transcriptor::read_with_callouts(&note("ownership_system.rs")).unwrap();
+        assert!(ownership.contains("> ⚠️ Error, `s` was moved\n"));
+        let borrowing = Transcriptor::read_with_callouts(&note("borrow_checker.rs")).unwrap();
+        assert!(borrowing.contains("> ⚠️ PROBLEM\n"));
+    }
+
+    #[test]
+    fn every_read_points_at_tablet() {
+        let missing = Tablet::new("/no/such/other.rs", 0, 0);
+        let errors = [
+            Transcriptor::read_gfm(&missing).unwrap_err(),
+            Transcriptor::read_html(&missing).unwrap_err(),
+            Transcriptor::read_without_code(&missing).unwrap_err(),
+            Transcriptor::read_wrapped(&missing, 10).unwrap_err(),
+            Transcriptor::toc(&missing).unwrap_err(),
+            Transcriptor::read_range(missing.path(), 0, 0).unwrap_err(),
+        ];
+        for err in errors {
+            assert_eq!(
+                (err.path(), err.kind()),
+                ("/no/such/other.rs", ErrorKind::NotFound)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "starts after its end")]
+    fn reversed_tablet_range_panics() {
+        Tablet::new("note.rs", 7, 3);
+    }
+
+    #[test]
+    fn custom_registry_rejects_bad_paths() {
+        let missing = RegistryBuilder::new()
+            .add_path("/no/such/custom.rs")
+            .build()
+            .unwrap_err();
+        assert_eq!(missing.kind(), ErrorKind::NotFound);
+        assert!(missing.to_string().contains("/no/such/custom.rs"));
+        let empty = RegistryBuilder::new()
+            .add_path("memory/empty.rs")
+            .build_with(InMemoryProvider::new().add("memory/empty.rs", ""));
+        assert_eq!(empty.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn lookups_share_one_snapshot() {
+        let catalog = Registry::catalog();
+        let shards: Vec<Shard> = catalog.iter().flat_map(Tablet::shards).collect();
+        assert_eq!(shards, Registry::heap());
+        assert_eq!(Registry::shard_index(&shards[1]).unwrap(), Some(1));
+        assert_eq!(Registry::shard_by_index(1), Some(shards[1]));
+        assert_eq!(
+            Registry::try_iter()
+                .collect::<Result<Vec<Tablet>>>()
+                .unwrap(),
+            catalog
+        );
+        let name = catalog[0].name();
+        assert_eq!(name.parse::<Tablet>().unwrap(), catalog[0]);
+    }
+
+    #[test]
+    #[cfg(feature = "strict-shard")]
+    fn strict_shards_convert() {
+        let tablet = fixture("strict", "//! # One\n//! -----\n//! Two");
+        let shards: Vec<strict::Shard> = tablet.shards().strict().collect();
+        assert_eq!(Transcriptor::read(&shards[1]).unwrap(), "Two");
+        assert_eq!(Tablet::from(shards[0]), Tablet(tablet.path_str(), (0, 0)));
+        assert_eq!(
+            strict::Shard::try_from(shards[1].tablet()).unwrap(),
+            shards[1]
+        );
+        let whole = strict::Shard::try_from(tablet).unwrap_err();
+        assert_eq!(whole.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn is_empty_ignores_blank_lines_and_metadata() {
+        let blank = fixture("blank_note", "//!\n//!   \n//! @tags: a\n//!");
+        assert!(blank.is_empty().unwrap());
+        assert!(!fixture("filled_note", "//!\n//! Text").is_empty().unwrap());
+    }
+
+    #[test]
+    fn lines_containing_ignores_case() {
+        let tablet = fixture(
+            "find_in_topic",
+            "//! # One\n//! -----\n//! Needle here\n//! no\n//! NEEDLE",
+        );
+        let shard = tablet.shards().nth(1).unwrap();
+        assert_eq!(
+            shard.lines_containing("needle").unwrap(),
+            vec![(2, "Needle here".to_string()), (4, "NEEDLE".to_string())]
+        );
+        assert!(shard.lines_containing("one").unwrap().is_empty());
+    }
+
+    #[test]
+    fn catalog_map_keeps_last_of_shared_names() {
+        let map = Registry::catalog_map();
+        let names: HashSet<&str> = Registry::catalog().iter().map(Tablet::name).collect();
+        assert_eq!(map.len(), names.len());
+        for (name, tablet) in &map {
+            assert_eq!(Registry::find_all_by_name(name).last(), Some(tablet));
+        }
+    }
+
+    #[test]
+    fn path_exists_without_opening() {
+        assert!(fixture("exists", "//! Here").path_exists());
+        assert!(!Tablet::new("/no/such/gone.rs", 0, 0).path_exists());
+    }
+
+    #[test]
+    fn catalog_by_shard_count_is_ranked() {
+        let ranked = Registry::catalog_by_shard_count().unwrap();
+        assert_eq!(ranked.len(), Registry::catalog().len());
+        for (tablet, count) in &ranked {
+            assert_eq!(tablet.shards().count(), *count);
+        }
+        for pair in ranked.windows(2) {
+            let ((a, a_count), (b, b_count)) = (pair[0], pair[1]);
+            assert!(a_count > b_count || (a_count == b_count && a.name() <= b.name()));
+        }
+    }
+
+    #[test]
+    fn line_at_respects_range() {
+        let tablet = fixture("line_at", "//! # One\n//! -----\n//! Two\n//! Three");
+        let shard = tablet.shards().nth(1).unwrap();
+        assert_eq!(shard.line_at(1).unwrap(), None);
+        assert_eq!(shard.line_at(2).unwrap().as_deref(), Some("Two"));
+        assert_eq!(shard.line_at(3).unwrap().as_deref(), Some("Three"));
+        assert_eq!(shard.line_at(4).unwrap(), None);
+    }
+
+    #[test]
+    fn read_bytes_are_utf8_of_read() {
+        let tablet = fixture(
+            "bytes",
+            "//! Ünïcode — text\n//! ```\n//! let a = 1;\n//! ```",
+        );
+        let bytes = Transcriptor::read_bytes(&tablet).unwrap();
+        assert_eq!(bytes, Transcriptor::read(&tablet).unwrap().into_bytes());
+        assert!(bytes.starts_with("Ünïcode".as_bytes()));
+    }
+
+    #[test]
+    fn enumerated_lines_are_absolute() {
+        let tablet = fixture("enumerated", "//! # One\n//! -----\n//! Two\n//!   Three");
+        let shard = tablet.shards().nth(1).unwrap();
+        assert_eq!(
+            shard.enumerated_lines().unwrap(),
+            vec![(2, "Two".to_string()), (3, "Three".to_string())]
+        );
+    }
+
+    #[test]
+    fn reading_position_of_shards() {
+        let tablet = fixture("position", "//! a\n//! -----\n//! b\n//! c");
+        let positions: Vec<f64> = tablet
+            .shards()
+            .map(|shard| shard.reading_position().unwrap())
+            .collect();
+        assert_eq!(positions, vec![0.0, 0.5]);
+    }
+
+    #[test]
+    fn rendered_matches_catalog_reads() {
+        let rendered = Registry::rendered()
+            .collect::<Result<Vec<(String, String)>>>()
+            .unwrap();
+        let expected: Vec<(String, String)> = Registry::catalog()
+            .iter()
+            .map(|tablet| {
+                (
+                    tablet.name().to_string(),
+                    Transcriptor::read(tablet).unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn topic_graph_edges_are_unique_links() {
+        let edges = Registry::topic_graph().unwrap();
+        assert!(!edges.is_empty());
+        let unique: HashSet<&(String, String)> = edges.iter().collect();
+        assert_eq!(unique.len(), edges.len());
+        for (name, link) in &edges {
+            let tablets = Registry::find_all_by_name(name);
+            assert!(
+                tablets
+                    .iter()
+                    .any(|tablet| tablet.links().unwrap().contains(link))
+            );
+        }
+    }
+
+    #[test]
+    fn catalog_chunks_first_only() {
+        let first = Registry::catalog_chunks(3).next().unwrap();
+        assert_eq!(first, Registry::catalog()[..3].to_vec());
+    }
+
+    #[test]
+    fn rendered_stops_early() {
+        let first = Registry::catalog()[0];
+        let (name, markdown) = Registry::rendered().next().unwrap().unwrap();
+        assert_eq!(name, first.name());
+        assert_eq!(markdown, Transcriptor::read(&first).unwrap());
+    }
+
+    #[test]
+    fn annotations_in_code_are_kept() {
+        let tablet = fixture(
+            "code_annotation",
+            "//! Body\n//! ```text\n//! @level: not metadata\n//! ```",
+        );
+        assert_eq!(
+            Transcriptor::read(&tablet).unwrap(),
+            "Body\n```text\n@level: not metadata\n```"
+        );
+        assert_eq!(tablet.level().unwrap(), None);
+    }
+
+    #[test]
+    fn read_wrapped_skips_annotations() {
+        let tablet = fixture(
+            "wrapped_tags",
+            "//! @tags: alpha, beta, gamma, delta, epsilon\n//! Short body",
+        );
+        assert_eq!(
+            Transcriptor::read_wrapped(&tablet, 20).unwrap(),
+            "Short body"
+        );
+    }
+
+    #[test]
+    fn read_html_escapes_fence_language() {
+        let tablet = fixture(
+            "html_lang",
+            "//! ```x\"><script>alert(1)</script>\n//! code\n//! ```",
+        );
+        let html = Transcriptor::read_html(&tablet).unwrap();
+        assert!(!html.contains("<script>"));
+        assert!(html.starts_with("<pre><code class=\"language-x&quot;&gt;&lt;script&gt;"));
+    }
+
+    #[test]
+    fn callouts_skip_urls_in_code() {
+        let tablet = fixture(
+            "callout_url",
+            "//! ```\n//! let url = \"http://error.example\";\n//! let a=b;// error: no space\n//! ```",
+        );
+        assert!(
+            !Transcriptor::read_with_callouts(&tablet)
+                .unwrap()
+                .contains('⚠')
+        );
+    }
+
+    #[test]
+    fn dedup_keeps_first_of_equal_contents() {
+        let first = fixture("dedup_first", "//! # Same\n//! Body");
+        let copy = fixture("dedup_copy", "//! # Same\n//! Body");
+        let other = fixture("dedup_other", "//! # Other");
+        assert_eq!(
+            Registry::dedup([first, copy, other]).unwrap(),
+            vec![first, other]
+        );
+        assert_eq!(Registry::catalog_dedup().unwrap(), Registry::catalog());
+    }
+
+    #[test]
+    fn bundled_notes_from_provider() {
+        let provider = Registry::paths()
+            .iter()
+            .fold(InMemoryProvider::new(), |provider, &path| {
+                provider.add(path, &std::fs::read_to_string(path).unwrap())
+            });
+        let bundled = Registry::with_provider(provider).unwrap();
+        assert_eq!(bundled.catalog(), Registry::catalog());
+        assert_eq!(bundled.heap(), Registry::heap());
+        let missing = Registry::with_provider(InMemoryProvider::new()).unwrap_err();
+        assert_eq!(missing.kind(), ErrorKind::NotFound);
     }
 }
@@ -0,0 +1,139 @@
+//! Pure line formatting and segmentation, shared by `Transcriptor` and usable without `std`.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// shortest line which separates two `Shard`s. Longer runs of dashes, like the usual `-----`, separate them too
+pub const SEPARATOR: &str = "---";
+/// opening and closing marker of a `markdown` code block
+pub const FENCE: &str = "```";
+
+/// formats one line to match `markdown` format
+pub fn line_fmt(line: &str) -> String {
+    let mut formatted = String::new();
+    formatted.push_str(
+        strip_doc_prefix(line)
+            .replace("//!", "")
+            .replace("```should_panic", "```rust")
+            .replace("```no_run", "```rust")
+            .trim(),
+    );
+    formatted.push('\n');
+    formatted
+}
+
+/// strips the leading inner `//!`, outer `///` or plain `//` comment marker, whichever comes first, along with the indentation before it
+pub fn strip_doc_prefix(line: &str) -> &str {
+    let line = line.trim_start();
+    ["//!", "///", "//"]
+        .iter()
+        .find_map(|prefix| line.strip_prefix(prefix))
+        .unwrap_or(line)
+}
+
+/// formats one line inside a code block, stripping only the comment marker and its single following space
+pub fn code_line_fmt(line: &str) -> String {
+    let line = strip_doc_prefix(line);
+    let line = line.strip_prefix(' ').unwrap_or(line);
+    line.trim_end().to_string()
+}
+
+/// finds all separators in the source `lines`, where the first line has the absolute number `start`.
+/// Dash lines inside code blocks, like a YAML `---` in a ```` ```text ```` block, do not separate
+pub fn segments<I>(lines: I, start: usize) -> Vec<(usize, usize)>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut segments: Vec<(usize, usize)> = Vec::new();
+    let mut ptr: usize = start;
+    let mut end: usize = start;
+    let mut in_code = false;
+    for line in lines {
+        let num = end;
+        end += 1;
+        let line = line_fmt(line.as_ref());
+        if is_fence(&line) {
+            in_code = !in_code;
+        } else if !in_code && is_formatted_separator(&line) {
+            if num > ptr {
+                segments.push((ptr, num - 1));
+            }
+            ptr = num + 1;
+        }
+    }
+    if ptr < end {
+        segments.push((ptr, end - 1));
+    }
+    segments
+}
+
+/// formats every source line like `Transcriptor::formatted_lines` does
+pub fn format_all(lines: &[String]) -> Vec<String> {
+    let mut in_code = false;
+    lines
+        .iter()
+        .map(|line| format_line(line, &mut in_code, true))
+        .collect()
+}
+
+/// marks every formatted line which belongs to a code block, fences included
+pub fn code_mask(lines: &[String]) -> Vec<bool> {
+    let mut in_code = false;
+    lines
+        .iter()
+        .map(|line| {
+            if is_fence(line) {
+                in_code = !in_code;
+                return true;
+            }
+            in_code
+        })
+        .collect()
+}
+
+/// splits the formatted `markdown` heading line into its level and text
+pub fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let text = &line[level..];
+    match level {
+        1..=6 if text.is_empty() || text.starts_with(' ') => Some((level, text.trim())),
+        _ => None,
+    }
+}
+
+/// checks if the whole source line, after formatting, is a separator between two `Shard`s
+pub fn is_separator(line: &str) -> bool {
+    is_formatted_separator(&line_fmt(line))
+}
+
+/// checks if the already formatted line is a separator between two `Shard`s: three or more dashes and nothing else.
+/// The line is not formatted again, so a `// -----` comment inside a code block is not a separator
+pub fn is_formatted_separator(line: &str) -> bool {
+    let line = line.trim();
+    line.starts_with(SEPARATOR) && line.chars().all(|c| c == '-')
+}
+
+/// checks if the formatted line opens or closes a code block
+pub fn is_fence(line: &str) -> bool {
+    line.starts_with(FENCE)
+}
+
+/// formats one source line, tracking whether it is inside a code block
+pub fn format_line(line: &str, in_code: &mut bool, preserve_code_indent: bool) -> String {
+    let formatted = line_fmt(line).trim_end().to_string();
+    if is_fence(&formatted) {
+        *in_code = !*in_code;
+    } else if *in_code && preserve_code_indent {
+        return code_line_fmt(line);
+    }
+    formatted
+}
+
+/// checks if the formatted line opens a `rust` code block, which is the default language of doc comments
+pub fn is_rust_fence(line: &str) -> bool {
+    match line.strip_prefix(FENCE) {
+        Some(lang) => lang.is_empty() || lang.split(',').any(|attr| attr.trim() == "rust"),
+        None => false,
+    }
+}
@@ -0,0 +1,64 @@
+//! Distinct `Shard` type, enabled by the optional `strict-shard` feature.
+//!
+//! [`crate::Shard`] is an alias of [`Tablet`], so a whole title is accepted wherever a single note is expected.
+//! The [`Shard`] of this module is a separate type, so such mistakes no longer compile. It derefs to the [`Tablet`]
+//! covering its lines, so [`crate::Transcriptor`] and every [`Tablet`] method still accept it
+
+use core::ops::Deref;
+
+use crate::{Shards, Tablet};
+
+/// `Shard` is a single note of the [`Tablet`], kept apart from the whole [`Tablet`] by the compiler.
+/// Obtained with [`Shards::strict`], or checked with `Shard::try_from(tablet)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shard(Tablet);
+
+impl Shard {
+    /// the [`Tablet`] covering the lines of this note
+    pub fn tablet(&self) -> Tablet {
+        self.0
+    }
+}
+
+impl Deref for Shard {
+    type Target = Tablet;
+
+    fn deref(&self) -> &Tablet {
+        &self.0
+    }
+}
+
+impl AsRef<Tablet> for Shard {
+    fn as_ref(&self) -> &Tablet {
+        &self.0
+    }
+}
+
+impl From<Shard> for Tablet {
+    fn from(shard: Shard) -> Self {
+        shard.0
+    }
+}
+
+/// Checks that the [`Tablet`] covers exactly one note, like [`Tablet::as_shard`].
+/// Fails with [`std::io::ErrorKind::InvalidInput`] if it spans several notes
+#[cfg(feature = "std")]
+impl TryFrom<Tablet> for Shard {
+    type Error = std::io::Error;
+
+    fn try_from(tablet: Tablet) -> std::io::Result<Self> {
+        tablet.as_shard()?.map(Shard).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "the tablet spans more than one shard",
+            )
+        })
+    }
+}
+
+impl Shards {
+    /// yields every [`crate::Shard`] as the strict [`Shard`] type
+    pub fn strict(self) -> impl Iterator<Item = Shard> {
+        self.map(Shard)
+    }
+}